@@ -0,0 +1,41 @@
+//! Compare throughput of serial and `rayon`-based parallel block encryption.
+//!
+//! Run with `cargo bench --features rayon --bench rayon`.
+
+use cipher::{Block, BlockDecrypt, BlockEncrypt};
+use std::time::{Duration, Instant};
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+use common::DummyParCipher;
+
+const BUF_LEN: usize = 16 << 20;
+const ROUNDS: u32 = 10;
+
+fn throughput(name: &str, mut f: impl FnMut()) {
+    f();
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    let elapsed: Duration = start.elapsed();
+    let mib = (BUF_LEN as f64 * ROUNDS as f64) / f64::from(1 << 20);
+    println!("{:<20} {:>10.1} MiB/s", name, mib / elapsed.as_secs_f64());
+}
+
+fn main() {
+    let mut blocks = vec![Block::<DummyParCipher>::default(); BUF_LEN / 16];
+
+    throughput("encrypt_blocks", || {
+        DummyParCipher.encrypt_blocks(&mut blocks)
+    });
+    throughput("par_encrypt_blocks", || {
+        DummyParCipher.par_encrypt_blocks(&mut blocks)
+    });
+    throughput("decrypt_blocks", || {
+        DummyParCipher.decrypt_blocks(&mut blocks)
+    });
+    throughput("par_decrypt_blocks", || {
+        DummyParCipher.par_decrypt_blocks(&mut blocks)
+    });
+}
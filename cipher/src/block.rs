@@ -10,10 +10,27 @@
 //! [2]: https://en.wikipedia.org/wiki/Block_cipher_mode_of_operation
 //! [3]: https://en.wikipedia.org/wiki/Symmetric-key_algorithm
 
-use crate::errors::InvalidLength;
+#[cfg(feature = "heapless")]
+use crate::errors::CapacityError;
+#[cfg(feature = "block-padding")]
+use crate::errors::PadError;
+use crate::errors::{BlockError, InvalidLength, NotEqualError};
 use crate::{FromKey, FromKeyNonce};
-use core::convert::TryInto;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "block-padding")]
+use block_padding::{PadType, Padding, UnpadError};
+use core::{marker::PhantomData, mem::MaybeUninit};
 use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+#[cfg(feature = "rayon")]
+use rayon::{iter::ParallelIterator, slice::ParallelSliceMut};
+#[cfg(feature = "subtle")]
+use subtle::{Choice, ConstantTimeEq};
+
+/// Number of "parallel" blocks processed by one thread in the rayon-based
+/// methods.
+#[cfg(feature = "rayon")]
+const RAYON_CHUNK_PAR_BLOCKS: usize = 256;
 
 /// Key for an algorithm that implements [`FromKey`].
 pub type BlockCipherKey<B> = GenericArray<u8, <B as FromKey>::KeySize>;
@@ -24,6 +41,9 @@ pub type Block<B> = GenericArray<u8, <B as BlockCipher>::BlockSize>;
 /// Block on which a [`BlockCipher`] operates in parallel.
 pub type ParBlocks<B> = GenericArray<Block<B>, <B as BlockCipher>::ParBlocks>;
 
+/// Tweak used by a [`TweakableBlockCipher`].
+pub type Tweak<B> = GenericArray<u8, <B as TweakableBlockCipher>::TweakSize>;
+
 /// Trait which marks a type as being a block cipher.
 pub trait BlockCipher {
     /// Size of the block in bytes
@@ -32,6 +52,198 @@ pub trait BlockCipher {
     /// Number of blocks which can be processed in parallel by
     /// cipher implementation
     type ParBlocks: ArrayLength<Block<Self>>;
+
+    /// Return number of blocks which can be processed in parallel by
+    /// cipher implementation.
+    #[inline(always)]
+    fn par_blocks() -> usize
+    where
+        Self: Sized,
+    {
+        Self::ParBlocks::USIZE
+    }
+}
+
+/// Trait for block ciphers which prefer a specific alignment of block buffers.
+///
+/// It's separate from [`BlockCipher`], since associated constants would
+/// make `dyn BlockEncrypt` and `dyn BlockDecrypt` impossible.
+pub trait BlockCipherAlign: BlockCipher {
+    /// Preferred alignment of block buffers in bytes.
+    ///
+    /// This is only a hint: implementations must correctly process buffers
+    /// with any alignment, but bulk helpers may copy misaligned input into
+    /// an aligned scratch buffer when it's beneficial for performance.
+    const PREFERRED_ALIGN: usize = 1;
+}
+
+/// Compare two blocks in constant time.
+///
+/// This function is intended for comparing cipher outputs, e.g. decrypted
+/// authentication blocks, without leaking their content via timing.
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+#[inline]
+pub fn ct_eq_blocks<C: BlockCipher>(a: &Block<C>, b: &Block<C>) -> Choice {
+    a.as_slice().ct_eq(b.as_slice())
+}
+
+/// Compare two slices of blocks in constant time.
+///
+/// Content of the blocks is compared in constant time, but slices with
+/// different lengths are considered unequal without inspecting their content.
+///
+/// This function is intended for comparing cipher outputs, e.g. decrypted
+/// authentication blocks, without leaking their content via timing.
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+#[inline]
+pub fn ct_eq_blocks_slice<C: BlockCipher>(a: &[Block<C>], b: &[Block<C>]) -> Choice {
+    if a.len() != b.len() {
+        return Choice::from(0);
+    }
+    a.iter()
+        .zip(b.iter())
+        .fold(Choice::from(1), |acc, (a, b)| acc & ct_eq_blocks::<C>(a, b))
+}
+
+/// Extension trait for viewing a [`Block`] as a byte slice in generic code.
+///
+/// ```
+/// use cipher::consts::{U1, U16};
+/// use cipher::{Block, BlockCipher, BlockExt};
+///
+/// struct Cipher;
+///
+/// impl BlockCipher for Cipher {
+///     type BlockSize = U16;
+///     type ParBlocks = U1;
+/// }
+///
+/// let mut block = Block::<Cipher>::default();
+/// block.as_bytes_mut()[0] = 1;
+/// assert_eq!(block.as_bytes()[..2], [1, 0]);
+/// ```
+pub trait BlockExt {
+    /// View block as a byte slice.
+    fn as_bytes(&self) -> &[u8];
+
+    /// View block as a mutable byte slice.
+    fn as_bytes_mut(&mut self) -> &mut [u8];
+}
+
+impl<N: ArrayLength<u8>> BlockExt for GenericArray<u8, N> {
+    #[inline]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    #[inline]
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+/// Convert byte slice into a block reference.
+///
+/// Returns [`InvalidLength`] if length of `buf` is not equal to the block
+/// size.
+#[inline]
+pub fn block_from_slice<C: BlockCipher>(buf: &[u8]) -> Result<&Block<C>, InvalidLength> {
+    if buf.len() != C::BlockSize::USIZE {
+        return Err(InvalidLength);
+    }
+    Ok(GenericArray::from_slice(buf))
+}
+
+/// Convert mutable byte slice into a mutable block reference.
+///
+/// Returns [`InvalidLength`] if length of `buf` is not equal to the block
+/// size.
+#[inline]
+pub fn block_from_mut_slice<C: BlockCipher>(
+    buf: &mut [u8],
+) -> Result<&mut Block<C>, InvalidLength> {
+    if buf.len() != C::BlockSize::USIZE {
+        return Err(InvalidLength);
+    }
+    Ok(GenericArray::from_mut_slice(buf))
+}
+
+/// View byte slice as a sequence of blocks.
+///
+/// Returns [`InvalidLength`] if length of `buf` is not multiple of the block
+/// size.
+///
+/// # Limitations
+/// The view is provided as an iterator over block references instead of
+/// a `&[Block<C>]` slice. Although `Block<C>` has the same layout as
+/// `[u8; BlockSize]`, `generic-array` v0.14 does not provide a safe way to
+/// reslice `&[u8]` as a slice of arrays, so this cast would require a pointer
+/// cast, which this crate avoids.
+/// For the same reason the cast can not be delegated to `bytemuck`, since
+/// `GenericArray` does not implement its `Pod` trait.
+///
+/// Blocks can be processed one by one using the returned iterator, e.g. with
+/// [`BlockEncrypt::encrypt_block`]. Slice-based methods such as
+/// [`BlockEncrypt::encrypt_blocks`] require the data to be copied into
+/// a buffer of blocks first.
+#[inline]
+pub fn as_blocks<C: BlockCipher>(
+    buf: &[u8],
+) -> Result<impl ExactSizeIterator<Item = &Block<C>> + '_, InvalidLength> {
+    let bs = C::BlockSize::USIZE;
+    if buf.len() % bs != 0 {
+        return Err(InvalidLength);
+    }
+    Ok(buf.chunks_exact(bs).map(GenericArray::from_slice))
+}
+
+/// View mutable byte slice as a sequence of mutable blocks.
+///
+/// Returns [`InvalidLength`] if length of `buf` is not multiple of the block
+/// size.
+///
+/// # Limitations
+/// The view is provided as an iterator over mutable block references instead
+/// of a `&mut [Block<C>]` slice, see [`as_blocks`] for details.
+#[inline]
+pub fn as_blocks_mut<C: BlockCipher>(
+    buf: &mut [u8],
+) -> Result<impl ExactSizeIterator<Item = &mut Block<C>> + '_, InvalidLength> {
+    let bs = C::BlockSize::USIZE;
+    if buf.len() % bs != 0 {
+        return Err(InvalidLength);
+    }
+    Ok(buf.chunks_exact_mut(bs).map(GenericArray::from_mut_slice))
+}
+
+/// Compile-time check that `N` is equal to the block size of `C`.
+///
+/// Referencing [`ArrayLen::CHECK`] fails compilation during
+/// monomorphization if the lengths are not equal.
+struct ArrayLen<C, const N: usize>(PhantomData<C>);
+
+impl<C: BlockCipher, const N: usize> ArrayLen<C, N> {
+    const CHECK: () = assert!(
+        N == C::BlockSize::USIZE,
+        "array length is not equal to block size"
+    );
+}
+
+/// Clone `src` blocks into uninitialized `out` buffer and return the
+/// initialized slice.
+///
+/// Lengths of `src` and `out` must be equal.
+#[allow(unsafe_code)]
+fn write_blocks<'o, B: Clone>(out: &'o mut [MaybeUninit<B>], src: &[B]) -> &'o mut [B] {
+    assert_eq!(out.len(), src.len());
+    for (o, b) in out.iter_mut().zip(src) {
+        o.write(b.clone());
+    }
+    // SAFETY: all elements of `out` were initialized by the loop above and
+    // `MaybeUninit<B>` has the same layout as `B`.
+    unsafe { &mut *(out as *mut [MaybeUninit<B>] as *mut [B]) }
 }
 
 /// Encrypt-only functionality for block ciphers.
@@ -39,6 +251,48 @@ pub trait BlockEncrypt: BlockCipher {
     /// Encrypt block in-place
     fn encrypt_block(&self, block: &mut Block<Self>);
 
+    /// Encrypt array in-place.
+    ///
+    /// Length of the array must be equal to the block size, otherwise
+    /// compilation fails:
+    ///
+    /// ```compile_fail
+    /// # use cipher::{consts::{U1, U16}, Block, BlockCipher, BlockEncrypt};
+    /// # struct Cipher;
+    /// # impl BlockCipher for Cipher { type BlockSize = U16; type ParBlocks = U1; }
+    /// # impl BlockEncrypt for Cipher { fn encrypt_block(&self, _: &mut Block<Self>) {} }
+    /// let mut block = [0u8; 8];
+    /// Cipher.encrypt_array(&mut block);
+    /// ```
+    #[inline]
+    fn encrypt_array<const N: usize>(&self, block: &mut [u8; N])
+    where
+        Self: Sized,
+    {
+        let () = ArrayLen::<Self, N>::CHECK;
+        self.encrypt_block(GenericArray::from_mut_slice(block));
+    }
+
+    /// Encrypt a copy of `block` and return the result.
+    #[inline]
+    fn encrypt_block_ret(&self, block: &Block<Self>) -> Block<Self> {
+        let mut res = block.clone();
+        self.encrypt_block(&mut res);
+        res
+    }
+
+    /// Lazily encrypt blocks yielded by `iter`.
+    ///
+    /// Each block is encrypted only when the returned iterator is advanced.
+    #[inline]
+    fn encrypt_from_iter<I>(&self, iter: I) -> impl Iterator<Item = Block<Self>>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Block<Self>>,
+    {
+        iter.into_iter().map(move |b| self.encrypt_block_ret(&b))
+    }
+
     /// Encrypt several blocks in parallel using instruction level parallelism
     /// if possible.
     ///
@@ -51,6 +305,11 @@ pub trait BlockEncrypt: BlockCipher {
     }
 
     /// Encrypt a slice of blocks, leveraging parallelism when available.
+    ///
+    /// Blocks are split into chunks of `ParBlocks` blocks processed by
+    /// [`encrypt_par_blocks`][Self::encrypt_par_blocks], while the remaining tail
+    /// is processed by [`encrypt_block`][Self::encrypt_block]. `ParBlocks` does
+    /// not have to be a power of two.
     #[inline]
     fn encrypt_blocks(&self, mut blocks: &mut [Block<Self>]) {
         let pb = Self::ParBlocks::to_usize();
@@ -59,7 +318,7 @@ pub trait BlockEncrypt: BlockCipher {
             let mut iter = blocks.chunks_exact_mut(pb);
 
             for chunk in &mut iter {
-                self.encrypt_par_blocks(chunk.try_into().unwrap())
+                self.encrypt_par_blocks(chunk.into())
             }
 
             blocks = iter.into_remainder();
@@ -69,6 +328,135 @@ pub trait BlockEncrypt: BlockCipher {
             self.encrypt_block(block);
         }
     }
+
+    /// Encrypt a slice of blocks and pass each resulting block to `f` in
+    /// order.
+    ///
+    /// Allows to fuse encryption with processing of the ciphertext, e.g.
+    /// feeding it to a universal hash in AEAD modes, without a second pass
+    /// over the data. Blocks are processed in the same way as by
+    /// [`encrypt_blocks`][Self::encrypt_blocks].
+    #[inline]
+    fn encrypt_blocks_and<F: FnMut(&Block<Self>)>(&self, mut blocks: &mut [Block<Self>], mut f: F)
+    where
+        Self: Sized,
+    {
+        let pb = Self::ParBlocks::to_usize();
+
+        if pb > 1 {
+            let mut iter = blocks.chunks_exact_mut(pb);
+
+            for chunk in &mut iter {
+                self.encrypt_par_blocks(chunk.into());
+                chunk.iter().for_each(&mut f);
+            }
+
+            blocks = iter.into_remainder();
+        }
+
+        for block in blocks {
+            self.encrypt_block(block);
+            f(block);
+        }
+    }
+
+    /// Encrypt a slice of blocks using multiple threads.
+    ///
+    /// Blocks are split into chunks which are processed in parallel on
+    /// the rayon thread pool using [`encrypt_blocks`][Self::encrypt_blocks].
+    /// Note that unlike [`encrypt_par_blocks`][Self::encrypt_par_blocks] this
+    /// method utilizes thread-level parallelism.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    fn par_encrypt_blocks(&self, blocks: &mut [Block<Self>])
+    where
+        Self: Sync,
+    {
+        let chunk_len = RAYON_CHUNK_PAR_BLOCKS * Self::ParBlocks::USIZE;
+        blocks
+            .par_chunks_mut(chunk_len)
+            .for_each(|chunk| self.encrypt_blocks(chunk));
+    }
+
+    /// Pad and encrypt message in-place and return length of the ciphertext.
+    ///
+    /// The message is stored in the first `msg_len` bytes of `buf`, the rest
+    /// of `buf` is used as spare capacity for padding. See
+    /// [`BlockEncryptMut::encrypt_padded_mut`] for details.
+    ///
+    /// Returns [`PadError`] if `buf` is too small to store the padded message
+    /// or if message length is not multiple of the block size while using
+    /// [`NoPadding`][block_padding::NoPadding].
+    #[cfg(feature = "block-padding")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "block-padding")))]
+    #[inline]
+    fn encrypt_buf_padded<P: Padding<Self::BlockSize>>(
+        &self,
+        buf: &mut [u8],
+        msg_len: usize,
+    ) -> Result<usize, PadError>
+    where
+        Self: Sized,
+    {
+        let mut cipher = self;
+        cipher
+            .encrypt_padded_mut::<P>(buf, msg_len)
+            .map(|ct| ct.len())
+    }
+
+    /// Encrypt `in_blocks` into uninitialized `out` buffer and return the
+    /// initialized output slice.
+    ///
+    /// Returns [`NotEqualError`] if lengths of `in_blocks` and `out` are not
+    /// equal.
+    #[inline]
+    fn encrypt_blocks_b2b_uninit<'o>(
+        &self,
+        in_blocks: &[Block<Self>],
+        out: &'o mut [MaybeUninit<Block<Self>>],
+    ) -> Result<&'o mut [Block<Self>], NotEqualError> {
+        if in_blocks.len() != out.len() {
+            return Err(NotEqualError);
+        }
+        let out = write_blocks(out, in_blocks);
+        self.encrypt_blocks(out);
+        Ok(out)
+    }
+
+    /// Encrypt a slice of blocks and return the result in a newly allocated
+    /// vector.
+    ///
+    /// This method allocates memory proportional to the input length, so it
+    /// is not suitable for constant-memory contexts.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn encrypt_blocks_to_vec(&self, blocks: &[Block<Self>]) -> Vec<Block<Self>> {
+        let mut res = blocks.to_vec();
+        self.encrypt_blocks(&mut res);
+        res
+    }
+
+    /// Encrypt a slice of blocks and return the result in a stack-allocated
+    /// vector with capacity of `N` blocks.
+    ///
+    /// Returns [`CapacityError`] if `blocks` contains more than `N` blocks.
+    #[cfg(feature = "heapless")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+    #[inline]
+    fn encrypt_blocks_to_heapless<const N: usize>(
+        &self,
+        blocks: &[Block<Self>],
+    ) -> Result<heapless::Vec<Block<Self>, N>, CapacityError>
+    where
+        Self: Sized,
+    {
+        let mut res = heapless::Vec::new();
+        res.extend_from_slice(blocks).map_err(|_| CapacityError)?;
+        self.encrypt_blocks(&mut res);
+        Ok(res)
+    }
 }
 
 /// Decrypt-only functionality for block ciphers.
@@ -76,6 +464,48 @@ pub trait BlockDecrypt: BlockCipher {
     /// Decrypt block in-place
     fn decrypt_block(&self, block: &mut Block<Self>);
 
+    /// Decrypt array in-place.
+    ///
+    /// Length of the array must be equal to the block size, otherwise
+    /// compilation fails:
+    ///
+    /// ```compile_fail
+    /// # use cipher::{consts::{U1, U16}, Block, BlockCipher, BlockDecrypt};
+    /// # struct Cipher;
+    /// # impl BlockCipher for Cipher { type BlockSize = U16; type ParBlocks = U1; }
+    /// # impl BlockDecrypt for Cipher { fn decrypt_block(&self, _: &mut Block<Self>) {} }
+    /// let mut block = [0u8; 8];
+    /// Cipher.decrypt_array(&mut block);
+    /// ```
+    #[inline]
+    fn decrypt_array<const N: usize>(&self, block: &mut [u8; N])
+    where
+        Self: Sized,
+    {
+        let () = ArrayLen::<Self, N>::CHECK;
+        self.decrypt_block(GenericArray::from_mut_slice(block));
+    }
+
+    /// Decrypt a copy of `block` and return the result.
+    #[inline]
+    fn decrypt_block_ret(&self, block: &Block<Self>) -> Block<Self> {
+        let mut res = block.clone();
+        self.decrypt_block(&mut res);
+        res
+    }
+
+    /// Lazily decrypt blocks yielded by `iter`.
+    ///
+    /// Each block is decrypted only when the returned iterator is advanced.
+    #[inline]
+    fn decrypt_from_iter<I>(&self, iter: I) -> impl Iterator<Item = Block<Self>>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Block<Self>>,
+    {
+        iter.into_iter().map(move |b| self.decrypt_block_ret(&b))
+    }
+
     /// Decrypt several blocks in parallel using instruction level parallelism
     /// if possible.
     ///
@@ -88,6 +518,11 @@ pub trait BlockDecrypt: BlockCipher {
     }
 
     /// Decrypt a slice of blocks, leveraging parallelism when available.
+    ///
+    /// Blocks are split into chunks of `ParBlocks` blocks processed by
+    /// [`decrypt_par_blocks`][Self::decrypt_par_blocks], while the remaining tail
+    /// is processed by [`decrypt_block`][Self::decrypt_block]. `ParBlocks` does
+    /// not have to be a power of two.
     #[inline]
     fn decrypt_blocks(&self, mut blocks: &mut [Block<Self>]) {
         let pb = Self::ParBlocks::to_usize();
@@ -96,7 +531,7 @@ pub trait BlockDecrypt: BlockCipher {
             let mut iter = blocks.chunks_exact_mut(pb);
 
             for chunk in &mut iter {
-                self.decrypt_par_blocks(chunk.try_into().unwrap())
+                self.decrypt_par_blocks(chunk.into())
             }
 
             blocks = iter.into_remainder();
@@ -106,6 +541,78 @@ pub trait BlockDecrypt: BlockCipher {
             self.decrypt_block(block);
         }
     }
+
+    /// Decrypt a slice of blocks using multiple threads.
+    ///
+    /// Blocks are split into chunks which are processed in parallel on
+    /// the rayon thread pool using [`decrypt_blocks`][Self::decrypt_blocks].
+    /// Note that unlike [`decrypt_par_blocks`][Self::decrypt_par_blocks] this
+    /// method utilizes thread-level parallelism.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    #[inline]
+    fn par_decrypt_blocks(&self, blocks: &mut [Block<Self>])
+    where
+        Self: Sync,
+    {
+        let chunk_len = RAYON_CHUNK_PAR_BLOCKS * Self::ParBlocks::USIZE;
+        blocks
+            .par_chunks_mut(chunk_len)
+            .for_each(|chunk| self.decrypt_blocks(chunk));
+    }
+
+    /// Decrypt `in_blocks` into uninitialized `out` buffer and return the
+    /// initialized output slice.
+    ///
+    /// Returns [`NotEqualError`] if lengths of `in_blocks` and `out` are not
+    /// equal.
+    #[inline]
+    fn decrypt_blocks_b2b_uninit<'o>(
+        &self,
+        in_blocks: &[Block<Self>],
+        out: &'o mut [MaybeUninit<Block<Self>>],
+    ) -> Result<&'o mut [Block<Self>], NotEqualError> {
+        if in_blocks.len() != out.len() {
+            return Err(NotEqualError);
+        }
+        let out = write_blocks(out, in_blocks);
+        self.decrypt_blocks(out);
+        Ok(out)
+    }
+
+    /// Decrypt a slice of blocks and return the result in a newly allocated
+    /// vector.
+    ///
+    /// This method allocates memory proportional to the input length, so it
+    /// is not suitable for constant-memory contexts.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn decrypt_blocks_to_vec(&self, blocks: &[Block<Self>]) -> Vec<Block<Self>> {
+        let mut res = blocks.to_vec();
+        self.decrypt_blocks(&mut res);
+        res
+    }
+
+    /// Decrypt a slice of blocks and return the result in a stack-allocated
+    /// vector with capacity of `N` blocks.
+    ///
+    /// Returns [`CapacityError`] if `blocks` contains more than `N` blocks.
+    #[cfg(feature = "heapless")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+    #[inline]
+    fn decrypt_blocks_to_heapless<const N: usize>(
+        &self,
+        blocks: &[Block<Self>],
+    ) -> Result<heapless::Vec<Block<Self>, N>, CapacityError>
+    where
+        Self: Sized,
+    {
+        let mut res = heapless::Vec::new();
+        res.extend_from_slice(blocks).map_err(|_| CapacityError)?;
+        self.decrypt_blocks(&mut res);
+        Ok(res)
+    }
 }
 
 /// Encrypt-only functionality for block ciphers with mutable access to `self`.
@@ -115,6 +622,115 @@ pub trait BlockDecrypt: BlockCipher {
 pub trait BlockEncryptMut: BlockCipher {
     /// Encrypt block in-place
     fn encrypt_block_mut(&mut self, block: &mut Block<Self>);
+
+    /// Encrypt a copy of `block` and return the result.
+    #[inline]
+    fn encrypt_block_ret_mut(&mut self, block: &Block<Self>) -> Block<Self> {
+        let mut res = block.clone();
+        self.encrypt_block_mut(&mut res);
+        res
+    }
+
+    /// Encrypt block in-place and return its value before encryption.
+    #[inline]
+    fn encrypt_block_retain(&mut self, block: &mut Block<Self>) -> Block<Self> {
+        let res = block.clone();
+        self.encrypt_block_mut(block);
+        res
+    }
+
+    /// Encrypt a slice of blocks in-place and return number of processed blocks.
+    ///
+    /// Implementations backed by hardware may process fewer blocks than
+    /// requested, in which case only the returned number of leading blocks
+    /// is encrypted and the caller should retry with the rest. The default
+    /// implementation processes all blocks.
+    #[inline]
+    fn try_encrypt_blocks_mut(&mut self, blocks: &mut [Block<Self>]) -> Result<usize, BlockError> {
+        for block in blocks.iter_mut() {
+            self.encrypt_block_mut(block);
+        }
+        Ok(blocks.len())
+    }
+
+    /// Encrypt a slice of blocks and return the result in a newly allocated
+    /// vector.
+    ///
+    /// This method allocates memory proportional to the input length, so it
+    /// is not suitable for constant-memory contexts.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn encrypt_blocks_to_vec_mut(&mut self, blocks: &[Block<Self>]) -> Vec<Block<Self>> {
+        let mut res = blocks.to_vec();
+        for block in res.iter_mut() {
+            self.encrypt_block_mut(block);
+        }
+        res
+    }
+
+    /// Encrypt a slice of blocks and return the result in a stack-allocated
+    /// vector with capacity of `N` blocks.
+    ///
+    /// Returns [`CapacityError`] if `blocks` contains more than `N` blocks.
+    #[cfg(feature = "heapless")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+    #[inline]
+    fn encrypt_blocks_to_heapless_mut<const N: usize>(
+        &mut self,
+        blocks: &[Block<Self>],
+    ) -> Result<heapless::Vec<Block<Self>, N>, CapacityError>
+    where
+        Self: Sized,
+    {
+        let mut res = heapless::Vec::new();
+        res.extend_from_slice(blocks).map_err(|_| CapacityError)?;
+        for block in res.iter_mut() {
+            self.encrypt_block_mut(block);
+        }
+        Ok(res)
+    }
+
+    /// Pad and encrypt message in-place.
+    ///
+    /// The message is stored in the first `msg_len` bytes of `buf`. The method
+    /// pads it using the padding `P`, encrypts the resulting blocks and
+    /// returns ciphertext stored in `buf`.
+    ///
+    /// Returns [`PadError`] if `buf` is too small to store the padded message
+    /// or if message length is not multiple of the block size while using
+    /// [`NoPadding`][block_padding::NoPadding].
+    #[cfg(feature = "block-padding")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "block-padding")))]
+    #[inline]
+    fn encrypt_padded_mut<'a, P: Padding<Self::BlockSize>>(
+        &mut self,
+        buf: &'a mut [u8],
+        msg_len: usize,
+    ) -> Result<&'a [u8], PadError>
+    where
+        Self: Sized,
+    {
+        let bs = Self::BlockSize::USIZE;
+        let tail_len = msg_len % bs;
+        let blocks_len = msg_len - tail_len;
+        let res_len = match P::TYPE {
+            PadType::NoPadding if tail_len != 0 => return Err(PadError),
+            PadType::NoPadding => msg_len,
+            _ => blocks_len.checked_add(bs).ok_or(PadError)?,
+        };
+        let buf = buf.get_mut(..res_len).ok_or(PadError)?;
+        let (blocks, tail) = buf.split_at_mut(blocks_len);
+        for block in blocks.chunks_exact_mut(bs) {
+            self.encrypt_block_mut(GenericArray::from_mut_slice(block));
+        }
+        if !tail.is_empty() {
+            let block = GenericArray::from_mut_slice(tail);
+            P::pad(block, tail_len);
+            self.encrypt_block_mut(block);
+        }
+        Ok(buf)
+    }
 }
 
 /// Decrypt-only functionality for block ciphers with mutable access to `self`.
@@ -124,6 +740,102 @@ pub trait BlockEncryptMut: BlockCipher {
 pub trait BlockDecryptMut: BlockCipher {
     /// Decrypt block in-place
     fn decrypt_block_mut(&mut self, block: &mut Block<Self>);
+
+    /// Decrypt a copy of `block` and return the result.
+    #[inline]
+    fn decrypt_block_ret_mut(&mut self, block: &Block<Self>) -> Block<Self> {
+        let mut res = block.clone();
+        self.decrypt_block_mut(&mut res);
+        res
+    }
+
+    /// Decrypt a slice of blocks in-place and return number of processed blocks.
+    ///
+    /// Implementations backed by hardware may process fewer blocks than
+    /// requested, in which case only the returned number of leading blocks
+    /// is decrypted and the caller should retry with the rest. The default
+    /// implementation processes all blocks.
+    #[inline]
+    fn try_decrypt_blocks_mut(&mut self, blocks: &mut [Block<Self>]) -> Result<usize, BlockError> {
+        for block in blocks.iter_mut() {
+            self.decrypt_block_mut(block);
+        }
+        Ok(blocks.len())
+    }
+
+    /// Decrypt a slice of blocks and return the result in a newly allocated
+    /// vector.
+    ///
+    /// This method allocates memory proportional to the input length, so it
+    /// is not suitable for constant-memory contexts.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn decrypt_blocks_to_vec_mut(&mut self, blocks: &[Block<Self>]) -> Vec<Block<Self>> {
+        let mut res = blocks.to_vec();
+        for block in res.iter_mut() {
+            self.decrypt_block_mut(block);
+        }
+        res
+    }
+
+    /// Decrypt a slice of blocks and return the result in a stack-allocated
+    /// vector with capacity of `N` blocks.
+    ///
+    /// Returns [`CapacityError`] if `blocks` contains more than `N` blocks.
+    #[cfg(feature = "heapless")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+    #[inline]
+    fn decrypt_blocks_to_heapless_mut<const N: usize>(
+        &mut self,
+        blocks: &[Block<Self>],
+    ) -> Result<heapless::Vec<Block<Self>, N>, CapacityError>
+    where
+        Self: Sized,
+    {
+        let mut res = heapless::Vec::new();
+        res.extend_from_slice(blocks).map_err(|_| CapacityError)?;
+        for block in res.iter_mut() {
+            self.decrypt_block_mut(block);
+        }
+        Ok(res)
+    }
+
+    /// Decrypt and unpad ciphertext in-place.
+    ///
+    /// The method decrypts blocks stored in `buf`, removes padding `P` and
+    /// returns the resulting plaintext stored in `buf`.
+    ///
+    /// Returns [`UnpadError`] if length of `buf` is not multiple of the block
+    /// size or if decrypted message contains malformed padding.
+    #[cfg(feature = "block-padding")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "block-padding")))]
+    #[inline]
+    fn decrypt_padded_mut<'a, P: Padding<Self::BlockSize>>(
+        &mut self,
+        buf: &'a mut [u8],
+    ) -> Result<&'a [u8], UnpadError>
+    where
+        Self: Sized,
+    {
+        let bs = Self::BlockSize::USIZE;
+        if buf.len() % bs != 0 {
+            return Err(UnpadError);
+        }
+        for block in buf.chunks_exact_mut(bs) {
+            self.decrypt_block_mut(GenericArray::from_mut_slice(block));
+        }
+        let res_len = match (buf.len().checked_sub(bs), P::TYPE) {
+            (_, PadType::NoPadding) => buf.len(),
+            (Some(blocks_len), _) => {
+                let last_block = GenericArray::from_slice(&buf[blocks_len..]);
+                blocks_len + P::unpad(last_block)?.len()
+            }
+            (None, PadType::Ambiguous) => 0,
+            (None, PadType::Reversible) => return Err(UnpadError),
+        };
+        Ok(&buf[..res_len])
+    }
 }
 
 impl<Alg: BlockEncrypt> BlockEncryptMut for Alg {
@@ -138,6 +850,235 @@ impl<Alg: BlockDecrypt> BlockDecryptMut for Alg {
     }
 }
 
+/// Block cipher which supports both encryption and decryption.
+///
+/// This trait is automatically implemented for all types which implement
+/// [`BlockEncrypt`] and [`BlockDecrypt`].
+pub trait BlockCipherEncDec: BlockCipher + BlockEncrypt + BlockDecrypt {}
+
+impl<Alg: BlockEncrypt + BlockDecrypt> BlockCipherEncDec for Alg {}
+
+/// Block cipher which supports both encryption and decryption with mutable
+/// access to `self`.
+///
+/// This trait is automatically implemented for all types which implement
+/// [`BlockEncryptMut`] and [`BlockDecryptMut`].
+pub trait BlockCipherEncDecMut: BlockCipher + BlockEncryptMut + BlockDecryptMut {}
+
+impl<Alg: BlockEncryptMut + BlockDecryptMut> BlockCipherEncDecMut for Alg {}
+
+/// Trait which marks a type as being a [tweakable block cipher][1].
+///
+/// [1]: https://en.wikipedia.org/wiki/Block_cipher#Tweakable_block_ciphers
+pub trait TweakableBlockCipher: BlockCipher {
+    /// Size of the tweak in bytes
+    type TweakSize: ArrayLength<u8>;
+}
+
+/// Encrypt-only functionality for tweakable block ciphers.
+pub trait TweakableBlockEncrypt: TweakableBlockCipher {
+    /// Encrypt block in-place using the provided tweak.
+    fn encrypt_block_with_tweak(&self, tweak: &Tweak<Self>, block: &mut Block<Self>);
+
+    /// Encrypt a slice of blocks, each with its own tweak.
+    ///
+    /// Implementations may override this method to process several blocks
+    /// in parallel.
+    ///
+    /// Returns [`NotEqualError`] without modifying `blocks` if `tweaks`
+    /// and `blocks` have different lengths.
+    #[inline]
+    fn encrypt_blocks_with_tweaks(
+        &self,
+        tweaks: &[Tweak<Self>],
+        blocks: &mut [Block<Self>],
+    ) -> Result<(), NotEqualError> {
+        if tweaks.len() != blocks.len() {
+            return Err(NotEqualError);
+        }
+        for (tweak, block) in tweaks.iter().zip(blocks.iter_mut()) {
+            self.encrypt_block_with_tweak(tweak, block);
+        }
+        Ok(())
+    }
+}
+
+/// Decrypt-only functionality for tweakable block ciphers.
+pub trait TweakableBlockDecrypt: TweakableBlockCipher {
+    /// Decrypt block in-place using the provided tweak.
+    fn decrypt_block_with_tweak(&self, tweak: &Tweak<Self>, block: &mut Block<Self>);
+
+    /// Decrypt a slice of blocks, each with its own tweak.
+    ///
+    /// Implementations may override this method to process several blocks
+    /// in parallel.
+    ///
+    /// Returns [`NotEqualError`] without modifying `blocks` if `tweaks`
+    /// and `blocks` have different lengths.
+    #[inline]
+    fn decrypt_blocks_with_tweaks(
+        &self,
+        tweaks: &[Tweak<Self>],
+        blocks: &mut [Block<Self>],
+    ) -> Result<(), NotEqualError> {
+        if tweaks.len() != blocks.len() {
+            return Err(NotEqualError);
+        }
+        for (tweak, block) in tweaks.iter().zip(blocks.iter_mut()) {
+            self.decrypt_block_with_tweak(tweak, block);
+        }
+        Ok(())
+    }
+}
+
+/// Object-safe encrypt-only functionality for block ciphers.
+///
+/// Unlike [`BlockEncrypt`] this trait is parameterized only by block size,
+/// which allows to use different block ciphers with the same block size
+/// behind a trait object, e.g. `Box<dyn DynBlockEncrypt<BlockSize = U16>>`.
+///
+/// This trait is automatically implemented for all types which implement
+/// [`BlockEncrypt`].
+pub trait DynBlockEncrypt {
+    /// Size of the block in bytes
+    type BlockSize: ArrayLength<u8>;
+
+    /// Encrypt block in-place
+    fn dyn_encrypt_block(&self, block: &mut GenericArray<u8, Self::BlockSize>);
+
+    /// Encrypt a slice of blocks, leveraging parallelism when available.
+    fn dyn_encrypt_blocks(&self, blocks: &mut [GenericArray<u8, Self::BlockSize>]);
+}
+
+impl<Alg: BlockEncrypt> DynBlockEncrypt for Alg {
+    type BlockSize = Alg::BlockSize;
+
+    #[inline]
+    fn dyn_encrypt_block(&self, block: &mut Block<Self>) {
+        self.encrypt_block(block);
+    }
+
+    #[inline]
+    fn dyn_encrypt_blocks(&self, blocks: &mut [Block<Self>]) {
+        self.encrypt_blocks(blocks);
+    }
+}
+
+/// Object-safe decrypt-only functionality for block ciphers.
+///
+/// Unlike [`BlockDecrypt`] this trait is parameterized only by block size,
+/// which allows to use different block ciphers with the same block size
+/// behind a trait object, e.g. `Box<dyn DynBlockDecrypt<BlockSize = U16>>`.
+///
+/// This trait is automatically implemented for all types which implement
+/// [`BlockDecrypt`].
+pub trait DynBlockDecrypt {
+    /// Size of the block in bytes
+    type BlockSize: ArrayLength<u8>;
+
+    /// Decrypt block in-place
+    fn dyn_decrypt_block(&self, block: &mut GenericArray<u8, Self::BlockSize>);
+
+    /// Decrypt a slice of blocks, leveraging parallelism when available.
+    fn dyn_decrypt_blocks(&self, blocks: &mut [GenericArray<u8, Self::BlockSize>]);
+}
+
+impl<Alg: BlockDecrypt> DynBlockDecrypt for Alg {
+    type BlockSize = Alg::BlockSize;
+
+    #[inline]
+    fn dyn_decrypt_block(&self, block: &mut Block<Self>) {
+        self.decrypt_block(block);
+    }
+
+    #[inline]
+    fn dyn_decrypt_blocks(&self, blocks: &mut [Block<Self>]) {
+        self.decrypt_blocks(blocks);
+    }
+}
+
+/// Trait for block ciphers with block size selected at runtime, e.g. full
+/// Rijndael or Threefish.
+///
+/// Unlike [`BlockCipher`] the block size is not fixed per type, so blocks are
+/// passed as byte slices with length validated against
+/// [`block_size`][Self::block_size].
+pub trait VariableBlockCipher {
+    /// Block sizes in bytes supported by the algorithm.
+    const BLOCK_SIZES: &'static [usize];
+
+    /// Size of the block in bytes selected for this instance.
+    fn block_size(&self) -> usize;
+
+    /// Encrypt block in-place.
+    ///
+    /// Length of `block` is guaranteed to be equal to `self.block_size()`.
+    fn encrypt_block_inner(&self, block: &mut [u8]);
+
+    /// Decrypt block in-place.
+    ///
+    /// Length of `block` is guaranteed to be equal to `self.block_size()`.
+    fn decrypt_block_inner(&self, block: &mut [u8]);
+
+    /// Check if `block_size` is supported by the algorithm.
+    #[inline]
+    fn is_block_size_supported(block_size: usize) -> bool {
+        Self::BLOCK_SIZES.contains(&block_size)
+    }
+
+    /// Encrypt block in-place.
+    ///
+    /// Returns an error if length of `block` is not equal to the block size.
+    #[inline]
+    fn encrypt_var_block(&self, block: &mut [u8]) -> Result<(), InvalidLength> {
+        if block.len() != self.block_size() {
+            return Err(InvalidLength);
+        }
+        self.encrypt_block_inner(block);
+        Ok(())
+    }
+
+    /// Decrypt block in-place.
+    ///
+    /// Returns an error if length of `block` is not equal to the block size.
+    #[inline]
+    fn decrypt_var_block(&self, block: &mut [u8]) -> Result<(), InvalidLength> {
+        if block.len() != self.block_size() {
+            return Err(InvalidLength);
+        }
+        self.decrypt_block_inner(block);
+        Ok(())
+    }
+
+    /// Encrypt blocks in-place.
+    ///
+    /// Returns an error if length of `buf` is not multiple of the block size.
+    #[inline]
+    fn encrypt_var_blocks(&self, buf: &mut [u8]) -> Result<(), InvalidLength> {
+        let bs = self.block_size();
+        if buf.len() % bs != 0 {
+            return Err(InvalidLength);
+        }
+        buf.chunks_exact_mut(bs)
+            .for_each(|block| self.encrypt_block_inner(block));
+        Ok(())
+    }
+
+    /// Decrypt blocks in-place.
+    ///
+    /// Returns an error if length of `buf` is not multiple of the block size.
+    #[inline]
+    fn decrypt_var_blocks(&self, buf: &mut [u8]) -> Result<(), InvalidLength> {
+        let bs = self.block_size();
+        if buf.len() % bs != 0 {
+            return Err(InvalidLength);
+        }
+        buf.chunks_exact_mut(bs)
+            .for_each(|block| self.decrypt_block_inner(block));
+        Ok(())
+    }
+}
+
 // Impls of block cipher traits for reference types
 
 impl<Alg: BlockCipher> BlockCipher for &Alg {
@@ -145,6 +1086,10 @@ impl<Alg: BlockCipher> BlockCipher for &Alg {
     type ParBlocks = Alg::ParBlocks;
 }
 
+impl<Alg: BlockCipherAlign> BlockCipherAlign for &Alg {
+    const PREFERRED_ALIGN: usize = Alg::PREFERRED_ALIGN;
+}
+
 impl<Alg: BlockEncrypt> BlockEncrypt for &Alg {
     #[inline]
     fn encrypt_block(&self, block: &mut Block<Self>) {
@@ -179,6 +1124,58 @@ impl<Alg: BlockDecrypt> BlockDecrypt for &Alg {
     }
 }
 
+/// Trait for ciphers which support in-place key ratcheting.
+///
+/// After [`ratchet`][Self::ratchet] the cipher uses a new key derived from
+/// its current state, so output produced before the ratchet can not be
+/// recomputed from the new state. Stream ciphers should also reset their
+/// keystream position.
+///
+/// Block ciphers can implement this trait using [`block_cipher_ratchet`]:
+///
+/// ```
+/// # use cipher::{consts::{U1, U16}, Block, BlockCipher, BlockEncrypt, FromKey};
+/// # use cipher::generic_array::GenericArray;
+/// use cipher::{block_cipher_ratchet, Ratchet};
+///
+/// # struct MyCipher;
+/// # impl BlockCipher for MyCipher { type BlockSize = U16; type ParBlocks = U1; }
+/// # impl BlockEncrypt for MyCipher { fn encrypt_block(&self, _: &mut Block<Self>) {} }
+/// # impl FromKey for MyCipher {
+/// #     type KeySize = U16;
+/// #     fn new(_: &GenericArray<u8, U16>) -> Self { MyCipher }
+/// # }
+/// impl Ratchet for MyCipher {
+///     fn ratchet(&mut self) {
+///         block_cipher_ratchet(self);
+///     }
+/// }
+/// ```
+pub trait Ratchet {
+    /// Replace key with a new one derived from the current state.
+    fn ratchet(&mut self);
+}
+
+/// Replace key of the block cipher with a new one derived from encryptions
+/// of blocks containing a little-endian counter.
+///
+/// Intended for implementing [`Ratchet`] for block ciphers.
+pub fn block_cipher_ratchet<C: FromKey + BlockEncrypt>(cipher: &mut C) {
+    let mut key = BlockCipherKey::<C>::default();
+    for (i, chunk) in key.chunks_mut(C::BlockSize::USIZE).enumerate() {
+        let mut block = Block::<C>::default();
+        block
+            .iter_mut()
+            .zip((i as u64).to_le_bytes())
+            .for_each(|(a, b)| *a = b);
+        cipher.encrypt_block(&mut block);
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+    *cipher = C::new(&key);
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(key.as_mut_slice());
+}
+
 /// Trait for types which can be initialized from a block cipher.
 pub trait FromBlockCipher {
     /// Block cipher used for initialization.
@@ -0,0 +1,199 @@
+//! Generic [CTR] mode adapter which turns a block cipher into a seekable
+//! stream cipher.
+//!
+//! [CTR]: https://en.wikipedia.org/wiki/Block_cipher_mode_of_operation#Counter_(CTR)
+
+use crate::errors::{LoopError, OverflowError};
+use crate::{
+    Block, BlockEncrypt, FromBlockCipherNonce, ParBlocks, SeekNum, StreamCipher,
+    StreamCipherCapabilities, StreamCipherSeek,
+};
+use core::marker::PhantomData;
+use generic_array::{typenum::Unsigned, GenericArray};
+
+/// Trait which defines how the counter is stored in the counter block.
+///
+/// The counter occupies the last [`WIDTH`][Self::WIDTH] bytes of the block,
+/// while the preceding bytes of the initial counter block (usually a nonce)
+/// stay unchanged. The counter wraps around at `2^(8 * WIDTH)`, so the
+/// keystream ends after this number of blocks.
+pub trait CtrFlavor {
+    /// Width of the counter in bytes. Must not be bigger than 16 bytes and
+    /// the cipher block size.
+    const WIDTH: usize;
+
+    /// Load counter from `bytes` of length [`WIDTH`][Self::WIDTH].
+    fn load(bytes: &[u8]) -> u128;
+
+    /// Store the counter truncated to [`WIDTH`][Self::WIDTH] bytes into
+    /// `bytes` of the same length.
+    fn store(ctr: u128, bytes: &mut [u8]);
+}
+
+macro_rules! impl_ctr_be_flavor {
+    ($name:ident, $t:ty, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Copy, Clone, Debug, Default)]
+        pub struct $name;
+
+        impl CtrFlavor for $name {
+            const WIDTH: usize = core::mem::size_of::<$t>();
+
+            #[inline]
+            fn load(bytes: &[u8]) -> u128 {
+                let mut buf = [0u8; core::mem::size_of::<$t>()];
+                buf.copy_from_slice(bytes);
+                <$t>::from_be_bytes(buf) as u128
+            }
+
+            #[inline]
+            fn store(ctr: u128, bytes: &mut [u8]) {
+                bytes.copy_from_slice(&(ctr as $t).to_be_bytes());
+            }
+        }
+    };
+}
+
+impl_ctr_be_flavor!(Ctr32BE, u32, "32-bit big endian counter flavor.");
+impl_ctr_be_flavor!(Ctr64BE, u64, "64-bit big endian counter flavor.");
+impl_ctr_be_flavor!(Ctr128BE, u128, "128-bit big endian counter flavor.");
+
+/// CTR mode stream cipher built on top of a block cipher.
+///
+/// The nonce is used as the initial counter block and the counter stored in
+/// it according to the flavor `F` is incremented for every keystream block.
+/// Keystream is generated by encrypting [`ParBlocks`] counter blocks at once
+/// using [`BlockEncrypt::encrypt_blocks`].
+///
+/// With a block cipher which implements [`FromKey`][crate::FromKey] it
+/// can be initialized with [`FromKeyNonce::new`][crate::FromKeyNonce::new].
+#[derive(Clone)]
+pub struct Ctr<C: BlockEncrypt, F: CtrFlavor> {
+    cipher: C,
+    nonce: Block<C>,
+    /// Number of the current keystream block.
+    block: u128,
+    /// Position inside the current keystream block.
+    pos: usize,
+    /// Keystream of the current block, valid only if `pos` is not zero.
+    buf: Block<C>,
+    _f: PhantomData<F>,
+}
+
+impl<C: BlockEncrypt, F: CtrFlavor> Ctr<C, F> {
+    /// Total number of blocks in the keystream.
+    ///
+    /// For 128-bit counters the real value does not fit into `u128`, so the
+    /// keystream is one block shorter than the counter period.
+    fn max_blocks() -> u128 {
+        match F::WIDTH {
+            16 => u128::MAX,
+            w => 1 << (8 * w),
+        }
+    }
+
+    fn counter_block(&self, block: u128) -> Block<C> {
+        let mut res = self.nonce.clone();
+        let ctr = &mut res[C::BlockSize::USIZE - F::WIDTH..];
+        F::store(F::load(ctr).wrapping_add(block), ctr);
+        res
+    }
+
+    fn xor(buf: &mut [u8], ks: &[u8]) {
+        buf.iter_mut().zip(ks).for_each(|(a, b)| *a ^= b);
+    }
+}
+
+impl<C: BlockEncrypt, F: CtrFlavor> FromBlockCipherNonce for Ctr<C, F> {
+    type BlockCipher = C;
+    type NonceSize = C::BlockSize;
+
+    fn from_block_cipher_nonce(cipher: C, nonce: &GenericArray<u8, C::BlockSize>) -> Self {
+        debug_assert!(F::WIDTH <= 16 && F::WIDTH <= C::BlockSize::USIZE);
+        Self {
+            cipher,
+            nonce: nonce.clone(),
+            block: 0,
+            pos: 0,
+            buf: Default::default(),
+            _f: PhantomData,
+        }
+    }
+}
+
+impl<C: BlockEncrypt, F: CtrFlavor> StreamCipher for Ctr<C, F> {
+    fn try_apply_keystream(&mut self, mut data: &mut [u8]) -> Result<(), LoopError> {
+        let bs = C::BlockSize::USIZE;
+        let needed = (self.pos as u128 + data.len() as u128).div_ceil(bs as u128);
+        if needed > Self::max_blocks() - self.block {
+            return Err(LoopError);
+        }
+
+        if self.pos != 0 {
+            let n = data.len().min(bs - self.pos);
+            let (head, tail) = data.split_at_mut(n);
+            Self::xor(head, &self.buf[self.pos..]);
+            self.pos += n;
+            if self.pos == bs {
+                self.pos = 0;
+                self.block += 1;
+            }
+            data = tail;
+        }
+
+        let mut ks = ParBlocks::<C>::default();
+        let mut chunks = data.chunks_exact_mut(bs * ks.len());
+        for chunk in &mut chunks {
+            for (i, b) in ks.iter_mut().enumerate() {
+                *b = self.counter_block(self.block + i as u128);
+            }
+            self.cipher.encrypt_blocks(&mut ks);
+            for (c, b) in chunk.chunks_exact_mut(bs).zip(ks.iter()) {
+                Self::xor(c, b);
+            }
+            self.block += ks.len() as u128;
+        }
+
+        let mut blocks = chunks.into_remainder().chunks_exact_mut(bs);
+        for c in &mut blocks {
+            let mut b = self.counter_block(self.block);
+            self.cipher.encrypt_block(&mut b);
+            Self::xor(c, &b);
+            self.block += 1;
+        }
+
+        let rem = blocks.into_remainder();
+        if !rem.is_empty() {
+            self.buf = self.counter_block(self.block);
+            self.cipher.encrypt_block(&mut self.buf);
+            Self::xor(rem, &self.buf);
+            self.pos = rem.len();
+        }
+        Ok(())
+    }
+}
+
+impl<C: BlockEncrypt, F: CtrFlavor> StreamCipherCapabilities for Ctr<C, F> {
+    const IS_SEEKABLE: bool = true;
+}
+
+impl<C: BlockEncrypt, F: CtrFlavor> StreamCipherSeek for Ctr<C, F> {
+    fn try_current_pos<T: SeekNum>(&self) -> Result<T, OverflowError> {
+        T::from_block_byte(self.block, self.pos as u8, C::BlockSize::U8)
+    }
+
+    fn try_seek<T: SeekNum>(&mut self, pos: T) -> Result<(), LoopError> {
+        let (block, pos): (u128, u8) = pos.to_block_byte(C::BlockSize::U8)?;
+        let max = Self::max_blocks();
+        if block > max || (block == max && pos != 0) {
+            return Err(LoopError);
+        }
+        self.block = block;
+        self.pos = pos as usize;
+        if self.pos != 0 {
+            self.buf = self.counter_block(block);
+            self.cipher.encrypt_block(&mut self.buf);
+        }
+        Ok(())
+    }
+}
@@ -1,2 +1,5 @@
 mod block;
+mod counting;
 mod stream;
+
+pub use counting::CountingCipher;
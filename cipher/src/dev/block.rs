@@ -106,6 +106,297 @@ macro_rules! block_cipher_test {
     };
 }
 
+/// Define block cipher test which checks that parallel block processing
+/// produces the same results as the single block methods
+///
+/// Each test vector is processed with
+/// [`encrypt_par_blocks`][crate::BlockEncrypt::encrypt_par_blocks] and
+/// [`decrypt_par_blocks`][crate::BlockDecrypt::decrypt_par_blocks] with
+/// the remaining `ParBlocks` slots filled with dummy blocks derived from the
+/// plaintext, and with [`encrypt_blocks`][crate::BlockEncrypt::encrypt_blocks]
+/// and [`decrypt_blocks`][crate::BlockDecrypt::decrypt_blocks] over inputs
+/// of different lengths including a tail shorter than `ParBlocks`.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+macro_rules! block_par_test {
+    ($name:ident, $test_name:expr, $cipher:ty) => {
+        #[test]
+        fn $name() {
+            use cipher::generic_array::{typenum::Unsigned, GenericArray};
+            use cipher::{blobby::Blob3Iterator, BlockCipher, BlockDecrypt, BlockEncrypt, FromKey};
+
+            type ParBlocks = <$cipher as BlockCipher>::ParBlocks;
+            type BlockSize = <$cipher as BlockCipher>::BlockSize;
+            type Block = GenericArray<u8, BlockSize>;
+            type ParBlock = GenericArray<Block, ParBlocks>;
+
+            fn run_test(key: &[u8], pt: &[u8], ct: &[u8]) -> bool {
+                let state = <$cipher as FromKey>::new_from_slice(key).unwrap();
+                let pb = ParBlocks::to_usize();
+
+                // fill all slots except the first one with dummy blocks
+                let mut blocks = ParBlock::default();
+                for (i, b) in blocks.iter_mut().enumerate() {
+                    b.copy_from_slice(pt);
+                    b[0] = b[0].wrapping_add(i as u8);
+                }
+                let pt_blocks = blocks.clone();
+
+                let mut expected = blocks.clone();
+                for b in expected.iter_mut() {
+                    state.encrypt_block(b);
+                }
+                if ct != expected[0].as_slice() {
+                    return false;
+                }
+
+                state.encrypt_par_blocks(&mut blocks);
+                if blocks != expected {
+                    return false;
+                }
+                state.decrypt_par_blocks(&mut blocks);
+                if blocks != pt_blocks {
+                    return false;
+                }
+
+                // check full chunks together with tails of all lengths
+                for n in 1..=2 * pb + 1 {
+                    let mut blocks1: Vec<Block> = (0..n)
+                        .map(|i| {
+                            let mut b = Block::clone_from_slice(pt);
+                            b[0] = b[0].wrapping_add(i as u8);
+                            b
+                        })
+                        .collect();
+                    let pt_blocks = blocks1.clone();
+                    let mut blocks2 = blocks1.clone();
+
+                    state.encrypt_blocks(&mut blocks1);
+                    for b in blocks2.iter_mut() {
+                        state.encrypt_block(b);
+                    }
+                    if blocks1 != blocks2 {
+                        return false;
+                    }
+
+                    state.decrypt_blocks(&mut blocks1);
+                    if blocks1 != pt_blocks {
+                        return false;
+                    }
+                }
+
+                true
+            }
+
+            let data = include_bytes!(concat!("data/", $test_name, ".blb"));
+            for (i, row) in Blob3Iterator::new(data).unwrap().enumerate() {
+                let [key, pt, ct] = row.unwrap();
+                if !run_test(key, pt, ct) {
+                    panic!(
+                        "\n\
+                         Failed parallel test №{}\n\
+                         key:\t{:?}\n\
+                         plaintext:\t{:?}\n\
+                         ciphertext:\t{:?}\n",
+                        i, key, pt, ct,
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Define block cipher test which checks that decryption of encrypted blocks
+/// results in the original blocks
+///
+/// Inputs are generated deterministically from the block index. Note that
+/// this test only catches asymmetric bugs between encryption and decryption
+/// and it is not a replacement for known-answer test vectors.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+macro_rules! block_roundtrip_test {
+    ($name:ident, $cipher:ty, $key:expr) => {
+        #[test]
+        fn $name() {
+            use cipher::generic_array::GenericArray;
+            use cipher::{BlockCipher, BlockDecrypt, BlockEncrypt, FromKey};
+
+            type Block = GenericArray<u8, <$cipher as BlockCipher>::BlockSize>;
+
+            const N: usize = 256;
+
+            let state = <$cipher as FromKey>::new_from_slice(&$key[..]).unwrap();
+            let blocks: Vec<Block> = (0..N)
+                .map(|i| {
+                    let mut block = Block::default();
+                    for (j, b) in block.iter_mut().enumerate() {
+                        *b = (i.wrapping_mul(31).wrapping_add(j.wrapping_mul(17))) as u8;
+                    }
+                    block
+                })
+                .collect();
+
+            for (i, pt) in blocks.iter().enumerate() {
+                let mut block = pt.clone();
+                state.encrypt_block(&mut block);
+                state.decrypt_block(&mut block);
+                if &block != pt {
+                    panic!(
+                        "\n\
+                         Failed roundtrip test №{}\n\
+                         plaintext:\t{:?}\n\
+                         decrypted:\t{:?}\n",
+                        i, pt, block,
+                    );
+                }
+            }
+
+            let mut buf = blocks.clone();
+            state.encrypt_blocks(&mut buf);
+            state.decrypt_blocks(&mut buf);
+            assert_eq!(buf, blocks, "failed roundtrip test for slice of blocks");
+        }
+    };
+}
+
+/// Define block cipher test using test vectors stored as hex strings
+///
+/// Test vectors are passed as a slice of `(key, plaintext, ciphertext)`
+/// tuples of hex strings, e.g. copied from an RFC. Each string must contain
+/// exactly `2 * KeySize` (for keys) or `2 * BlockSize` (for blocks) upper- or
+/// lowercase hex digits without any separators or prefixes.
+///
+/// Unlike [`block_cipher_test`], this macro does not use `blobby` and
+/// decodes test vectors into fixed-size arrays, so it does not require
+/// `alloc`. It's still gated on the `dev` feature like the rest of the
+/// test macros.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+macro_rules! block_cipher_hex_test {
+    ($name:ident, $cipher:ty, $vectors:expr) => {
+        #[test]
+        fn $name() {
+            use cipher::generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+            use cipher::{Block, BlockCipherKey, BlockDecrypt, BlockEncrypt, FromKey};
+
+            fn decode_hex<N: ArrayLength<u8>>(s: &str) -> GenericArray<u8, N> {
+                fn nibble(c: u8) -> u8 {
+                    match c {
+                        b'0'..=b'9' => c - b'0',
+                        b'a'..=b'f' => c - b'a' + 10,
+                        b'A'..=b'F' => c - b'A' + 10,
+                        _ => panic!("invalid hex digit: {:?}", c as char),
+                    }
+                }
+                assert!(
+                    s.len() == 2 * N::USIZE,
+                    "invalid length of hex string: {:?}",
+                    s
+                );
+                let mut buf = GenericArray::default();
+                for (b, c) in buf.iter_mut().zip(s.as_bytes().chunks_exact(2)) {
+                    *b = (nibble(c[0]) << 4) | nibble(c[1]);
+                }
+                buf
+            }
+
+            fn run_test(
+                key: &BlockCipherKey<$cipher>,
+                pt: &Block<$cipher>,
+                ct: &Block<$cipher>,
+            ) -> bool {
+                let state = <$cipher as FromKey>::new(key);
+
+                let mut block = pt.clone();
+                state.encrypt_block(&mut block);
+                if ct != &block {
+                    return false;
+                }
+
+                state.decrypt_block(&mut block);
+                if pt != &block {
+                    return false;
+                }
+
+                true
+            }
+
+            let vectors: &[(&str, &str, &str)] = $vectors;
+            for (i, &(key, pt, ct)) in vectors.iter().enumerate() {
+                if !run_test(&decode_hex(key), &decode_hex(pt), &decode_hex(ct)) {
+                    panic!(
+                        "\n\
+                         Failed test №{}\n\
+                         key:\t{}\n\
+                         plaintext:\t{}\n\
+                         ciphertext:\t{}\n",
+                        i, key, pt, ct,
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Define test which checks that a variable block size cipher correctly
+/// round-trips data for every supported block size
+///
+/// `$new` must be an expression callable with a block size in bytes which
+/// returns the cipher instance for that size.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+macro_rules! variable_block_cipher_test {
+    ($name:ident, $cipher:ty, $new:expr) => {
+        #[test]
+        fn $name() {
+            use cipher::VariableBlockCipher;
+
+            const N: usize = 16;
+
+            let new = $new;
+            for &bs in <$cipher as VariableBlockCipher>::BLOCK_SIZES {
+                assert!(<$cipher as VariableBlockCipher>::is_block_size_supported(
+                    bs
+                ));
+                let state: $cipher = new(bs);
+                assert_eq!(state.block_size(), bs, "wrong block size");
+
+                let pt: Vec<u8> = (0..N * bs).map(|i| (i.wrapping_mul(31)) as u8).collect();
+
+                let mut buf = pt.clone();
+                for block in buf.chunks_exact_mut(bs) {
+                    state.encrypt_var_block(block).unwrap();
+                }
+                assert_ne!(buf, pt, "encryption is identity for block size {}", bs);
+                let mut buf2 = pt.clone();
+                state.encrypt_var_blocks(&mut buf2).unwrap();
+                assert_eq!(
+                    buf, buf2,
+                    "block and slice encryption mismatch for block size {}",
+                    bs
+                );
+
+                for block in buf.chunks_exact_mut(bs) {
+                    state.decrypt_var_block(block).unwrap();
+                }
+                assert_eq!(buf, pt, "failed roundtrip test for block size {}", bs);
+                state.decrypt_var_blocks(&mut buf2).unwrap();
+                assert_eq!(
+                    buf2, pt,
+                    "failed slice roundtrip test for block size {}",
+                    bs
+                );
+
+                let mut bad = vec![0u8; bs + 1];
+                assert!(state.encrypt_var_block(&mut bad).is_err());
+                assert!(state.decrypt_var_block(&mut bad[..bs - 1]).is_err());
+                assert!(state.encrypt_var_blocks(&mut bad).is_err());
+                assert!(state.decrypt_var_blocks(&mut bad).is_err());
+            }
+        }
+    };
+}
+
 /// Define block cipher benchmark
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
@@ -0,0 +1,107 @@
+use crate::{Block, BlockCipher, BlockCipherAlign, BlockDecrypt, BlockEncrypt, FromKey, ParBlocks};
+use core::cell::Cell;
+use generic_array::GenericArray;
+
+/// Block cipher wrapper which counts the number of processed blocks.
+///
+/// Intended for testing, e.g. to check that a mode of operation invokes
+/// the underlying cipher the expected number of times.
+#[derive(Debug, Default)]
+pub struct CountingCipher<C> {
+    cipher: C,
+    encrypted: Cell<u64>,
+    decrypted: Cell<u64>,
+}
+
+impl<C> CountingCipher<C> {
+    /// Wrap `cipher` with zeroed counters.
+    pub fn from_cipher(cipher: C) -> Self {
+        Self {
+            cipher,
+            encrypted: Cell::new(0),
+            decrypted: Cell::new(0),
+        }
+    }
+
+    /// Number of blocks encrypted so far.
+    pub fn encrypt_count(&self) -> u64 {
+        self.encrypted.get()
+    }
+
+    /// Number of blocks decrypted so far.
+    pub fn decrypt_count(&self) -> u64 {
+        self.decrypted.get()
+    }
+
+    /// Reset both counters to zero.
+    pub fn reset_counts(&self) {
+        self.encrypted.set(0);
+        self.decrypted.set(0);
+    }
+
+    /// Get the wrapped cipher.
+    pub fn into_inner(self) -> C {
+        self.cipher
+    }
+}
+
+impl<C: BlockCipher> BlockCipher for CountingCipher<C> {
+    type BlockSize = C::BlockSize;
+    type ParBlocks = C::ParBlocks;
+}
+
+impl<C: BlockCipherAlign> BlockCipherAlign for CountingCipher<C> {
+    const PREFERRED_ALIGN: usize = C::PREFERRED_ALIGN;
+}
+
+impl<C: FromKey> FromKey for CountingCipher<C> {
+    type KeySize = C::KeySize;
+
+    fn new(key: &GenericArray<u8, Self::KeySize>) -> Self {
+        Self::from_cipher(C::new(key))
+    }
+}
+
+impl<C: BlockEncrypt> BlockEncrypt for CountingCipher<C> {
+    #[inline]
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        self.encrypted.set(self.encrypted.get() + 1);
+        self.cipher.encrypt_block(block);
+    }
+
+    #[inline]
+    fn encrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        self.encrypted
+            .set(self.encrypted.get() + blocks.len() as u64);
+        self.cipher.encrypt_par_blocks(blocks);
+    }
+
+    #[inline]
+    fn encrypt_blocks(&self, blocks: &mut [Block<Self>]) {
+        self.encrypted
+            .set(self.encrypted.get() + blocks.len() as u64);
+        self.cipher.encrypt_blocks(blocks);
+    }
+}
+
+impl<C: BlockDecrypt> BlockDecrypt for CountingCipher<C> {
+    #[inline]
+    fn decrypt_block(&self, block: &mut Block<Self>) {
+        self.decrypted.set(self.decrypted.get() + 1);
+        self.cipher.decrypt_block(block);
+    }
+
+    #[inline]
+    fn decrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        self.decrypted
+            .set(self.decrypted.get() + blocks.len() as u64);
+        self.cipher.decrypt_par_blocks(blocks);
+    }
+
+    #[inline]
+    fn decrypt_blocks(&self, blocks: &mut [Block<Self>]) {
+        self.decrypted
+            .set(self.decrypted.get() + blocks.len() as u64);
+        self.cipher.decrypt_blocks(blocks);
+    }
+}
@@ -12,8 +12,7 @@ impl fmt::Display for LoopError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for LoopError {}
+impl core::error::Error for LoopError {}
 
 /// The error type returned when a cipher position can not be represented
 /// by the requested type.
@@ -32,8 +31,46 @@ impl From<OverflowError> for LoopError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for OverflowError {}
+impl core::error::Error for OverflowError {}
+
+/// The error type returned when slices passed to a method (e.g. input and
+/// output buffers of buffer-to-buffer methods) have different lengths.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct NotEqualError;
+
+impl fmt::Display for NotEqualError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Length mismatch")
+    }
+}
+
+impl core::error::Error for NotEqualError {}
+
+/// The error type returned when a buffer is too small to store a padded
+/// message or when a message can not be padded with the selected padding.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct PadError;
+
+impl fmt::Display for PadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Padding Error")
+    }
+}
+
+impl core::error::Error for PadError {}
+
+/// The error type returned when an output buffer with fixed capacity can not
+/// store the result.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct CapacityError;
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Capacity Error")
+    }
+}
+
+impl core::error::Error for CapacityError {}
 
 /// The error type returned when key and/or nonce used in the [`FromKey`]
 /// and [`FromKeyNonce`] slice-based methods had an invalid length.
@@ -49,8 +86,7 @@ impl fmt::Display for InvalidLength {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for InvalidLength {}
+impl core::error::Error for InvalidLength {}
 
 /// The error type returned by the [`BlockModeEncryptWrapper`] and
 /// [`BlockModeDecryptWrapper`] types.
@@ -66,5 +102,152 @@ impl fmt::Display for BlockModeError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for BlockModeError {}
+impl core::error::Error for BlockModeError {}
+
+/// The error type returned when a known-answer self-test fails.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct SelfTestError;
+
+impl fmt::Display for SelfTestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Self-Test Error")
+    }
+}
+
+impl core::error::Error for SelfTestError {}
+
+/// Error type which unifies errors returned by cipher initialization and
+/// keystream application, e.g. to use `?` on both in one function.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum CipherError {
+    /// Invalid key or nonce length.
+    InvalidLength,
+    /// End of a keystream was reached.
+    Loop,
+    /// Input and output buffers have different lengths.
+    NotEqual,
+}
+
+impl fmt::Display for CipherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            CipherError::InvalidLength => f.write_str("Invalid Length"),
+            CipherError::Loop => f.write_str("Loop Error"),
+            CipherError::NotEqual => f.write_str("Length mismatch"),
+        }
+    }
+}
+
+impl From<InvalidLength> for CipherError {
+    fn from(_: InvalidLength) -> CipherError {
+        CipherError::InvalidLength
+    }
+}
+
+impl From<crypto_common::InvalidLength> for CipherError {
+    fn from(_: crypto_common::InvalidLength) -> CipherError {
+        CipherError::InvalidLength
+    }
+}
+
+impl From<LoopError> for CipherError {
+    fn from(_: LoopError) -> CipherError {
+        CipherError::Loop
+    }
+}
+
+impl From<NotEqualError> for CipherError {
+    fn from(_: NotEqualError) -> CipherError {
+        CipherError::NotEqual
+    }
+}
+
+impl core::error::Error for CipherError {}
+
+/// The error type returned by [`KeyWrap`][crate::KeyWrap] methods.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum KeyWrapError {
+    /// Input length is not a multiple of 8 bytes or is too short.
+    InvalidDataLength,
+    /// Output buffer length does not match input length.
+    InvalidOutputLength,
+    /// Unwrapped initial value does not match, i.e. the wrapped key was
+    /// corrupted or wrapped with a different key-encryption key.
+    IntegrityCheckFailed,
+}
+
+impl fmt::Display for KeyWrapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            KeyWrapError::InvalidDataLength => f.write_str("Invalid Data Length"),
+            KeyWrapError::InvalidOutputLength => f.write_str("Invalid Output Length"),
+            KeyWrapError::IntegrityCheckFailed => f.write_str("Integrity Check Failed"),
+        }
+    }
+}
+
+impl core::error::Error for KeyWrapError {}
+
+/// The error type returned when a block cipher implementation (e.g. a
+/// hardware peripheral) fails to process blocks.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct BlockError;
+
+impl fmt::Display for BlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Block Error")
+    }
+}
+
+impl core::error::Error for BlockError {}
+
+#[cfg(feature = "defmt")]
+macro_rules! impl_defmt_format {
+    ($($ty:ty => $msg:literal,)*) => {
+        $(
+            #[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+            impl defmt::Format for $ty {
+                fn format(&self, f: defmt::Formatter<'_>) {
+                    defmt::write!(f, $msg)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "defmt")]
+impl_defmt_format!(
+    LoopError => "Loop Error",
+    OverflowError => "Overflow Error",
+    NotEqualError => "Length mismatch",
+    PadError => "Padding Error",
+    CapacityError => "Capacity Error",
+    InvalidLength => "Invalid Length",
+    BlockModeError => "Invalid Length",
+    SelfTestError => "Self-Test Error",
+    BlockError => "Block Error",
+);
+
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl defmt::Format for CipherError {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            CipherError::InvalidLength => defmt::write!(f, "Invalid Length"),
+            CipherError::Loop => defmt::write!(f, "Loop Error"),
+            CipherError::NotEqual => defmt::write!(f, "Length mismatch"),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+impl defmt::Format for KeyWrapError {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            KeyWrapError::InvalidDataLength => defmt::write!(f, "Invalid Data Length"),
+            KeyWrapError::InvalidOutputLength => defmt::write!(f, "Invalid Output Length"),
+            KeyWrapError::IntegrityCheckFailed => defmt::write!(f, "Integrity Check Failed"),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Key wrap construction defined in [RFC 3394].
+//!
+//! [RFC 3394]: https://www.rfc-editor.org/rfc/rfc3394
+
+use crate::errors::KeyWrapError;
+use crate::{Block, BlockCipher, BlockDecrypt, BlockEncrypt};
+use generic_array::typenum::U16;
+
+/// Size of a semiblock in bytes.
+const SEMIBLOCK: usize = 8;
+
+/// Default initial value defined in RFC 3394 section 2.2.3.1.
+pub const KEY_WRAP_IV: [u8; SEMIBLOCK] = [0xA6; SEMIBLOCK];
+
+/// Key wrapping ([RFC 3394]) on top of a block cipher with 128-bit block.
+///
+/// Key data is processed in 64-bit semiblocks, so its length must be a
+/// multiple of 8 bytes and it must contain at least two semiblocks. Wrapped
+/// data is one semiblock longer than the key data.
+///
+/// This trait is automatically implemented for all block ciphers with
+/// 128-bit block which implement both [`BlockEncrypt`] and [`BlockDecrypt`].
+///
+/// [RFC 3394]: https://www.rfc-editor.org/rfc/rfc3394
+pub trait KeyWrap {
+    /// Wrap `data` and write result into `out`.
+    ///
+    /// Returns an error if length of `data` is not a multiple of 8 bytes,
+    /// is smaller than 16 bytes, or if `out` is not exactly 8 bytes longer
+    /// than `data`.
+    fn wrap(&self, data: &[u8], out: &mut [u8]) -> Result<(), KeyWrapError>;
+
+    /// Unwrap `data` and write result into `out`.
+    ///
+    /// Returns an error if length of `data` is not a multiple of 8 bytes,
+    /// is smaller than 24 bytes, if `out` is not exactly 8 bytes shorter than
+    /// `data`, or if the integrity check fails. In the latter case `out` is
+    /// filled with zeros.
+    fn unwrap(&self, data: &[u8], out: &mut [u8]) -> Result<(), KeyWrapError>;
+}
+
+impl<C> KeyWrap for C
+where
+    C: BlockCipher<BlockSize = U16> + BlockEncrypt + BlockDecrypt,
+{
+    fn wrap(&self, data: &[u8], out: &mut [u8]) -> Result<(), KeyWrapError> {
+        if data.len() % SEMIBLOCK != 0 || data.len() < 2 * SEMIBLOCK {
+            return Err(KeyWrapError::InvalidDataLength);
+        }
+        if out.len() != data.len() + SEMIBLOCK {
+            return Err(KeyWrapError::InvalidOutputLength);
+        }
+
+        let n = data.len() / SEMIBLOCK;
+        let (a, r) = out.split_at_mut(SEMIBLOCK);
+        a.copy_from_slice(&KEY_WRAP_IV);
+        r.copy_from_slice(data);
+
+        let mut block = Block::<C>::default();
+        for j in 0..6 {
+            for (i, ri) in r.chunks_exact_mut(SEMIBLOCK).enumerate() {
+                block[..SEMIBLOCK].copy_from_slice(a);
+                block[SEMIBLOCK..].copy_from_slice(ri);
+                self.encrypt_block(&mut block);
+
+                let t = ((n * j + i + 1) as u64).to_be_bytes();
+                a.iter_mut()
+                    .zip(&block[..SEMIBLOCK])
+                    .zip(t)
+                    .for_each(|((a, b), t)| *a = b ^ t);
+                ri.copy_from_slice(&block[SEMIBLOCK..]);
+            }
+        }
+        Ok(())
+    }
+
+    fn unwrap(&self, data: &[u8], out: &mut [u8]) -> Result<(), KeyWrapError> {
+        if data.len() % SEMIBLOCK != 0 || data.len() < 3 * SEMIBLOCK {
+            return Err(KeyWrapError::InvalidDataLength);
+        }
+        if out.len() + SEMIBLOCK != data.len() {
+            return Err(KeyWrapError::InvalidOutputLength);
+        }
+
+        let n = out.len() / SEMIBLOCK;
+        let mut a = [0u8; SEMIBLOCK];
+        a.copy_from_slice(&data[..SEMIBLOCK]);
+        out.copy_from_slice(&data[SEMIBLOCK..]);
+
+        let mut block = Block::<C>::default();
+        for j in (0..6).rev() {
+            for (i, ri) in out.chunks_exact_mut(SEMIBLOCK).enumerate().rev() {
+                let t = ((n * j + i + 1) as u64).to_be_bytes();
+                block[..SEMIBLOCK]
+                    .iter_mut()
+                    .zip(a)
+                    .zip(t)
+                    .for_each(|((b, a), t)| *b = a ^ t);
+                block[SEMIBLOCK..].copy_from_slice(ri);
+                self.decrypt_block(&mut block);
+
+                a.copy_from_slice(&block[..SEMIBLOCK]);
+                ri.copy_from_slice(&block[SEMIBLOCK..]);
+            }
+        }
+
+        let diff = a
+            .iter()
+            .zip(KEY_WRAP_IV)
+            .fold(0, |acc, (a, iv)| acc | (a ^ iv));
+        if diff != 0 {
+            out.iter_mut().for_each(|b| *b = 0);
+            return Err(KeyWrapError::IntegrityCheckFailed);
+        }
+        Ok(())
+    }
+}
@@ -10,9 +10,11 @@
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/media/8f1a9894/logo.svg",
     html_favicon_url = "https://raw.githubusercontent.com/RustCrypto/media/8f1a9894/logo.svg"
 )]
-#![forbid(unsafe_code)]
+#![deny(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
@@ -20,25 +22,68 @@ extern crate std;
 #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
 pub use rand_core;
 
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use serde;
+
+#[cfg(feature = "subtle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subtle")))]
+pub use subtle;
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub use zeroize;
+
+#[cfg(feature = "heapless")]
+#[cfg_attr(docsrs, doc(cfg(feature = "heapless")))]
+pub use heapless;
+
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+pub use bytes;
+
 #[cfg(feature = "dev")]
 pub use blobby;
 
+#[cfg(feature = "block-padding")]
+#[cfg_attr(docsrs, doc(cfg(feature = "block-padding")))]
+pub use block_padding;
+
 mod block;
+mod ctr;
 #[cfg(feature = "dev")]
 mod dev;
 pub mod errors;
+mod key_wrap;
 mod mode;
 mod stream;
+#[cfg(feature = "async")]
+mod stream_async;
+mod stream_fmt;
+#[cfg(feature = "std")]
+mod stream_io;
 
 #[cfg(feature = "mode_wrapper")]
 mod mode_wrapper;
 
 pub use crate::{block::*, mode::*, stream::*};
+pub use ctr::{Ctr, Ctr128BE, Ctr32BE, Ctr64BE, CtrFlavor};
+#[cfg(feature = "dev")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+pub use dev::CountingCipher;
 pub use generic_array::{self, typenum::consts};
+pub use key_wrap::{KeyWrap, KEY_WRAP_IV};
 #[cfg(feature = "mode_wrapper")]
 pub use mode_wrapper::{BlockModeDecryptWrapper, BlockModeEncryptWrapper};
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub use stream_async::{StreamCipherSink, StreamCipherStream};
+pub use stream_fmt::StreamCipherFmtWriter;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use stream_io::{StreamCipherReader, StreamCipherWriter};
 
-use crate::errors::InvalidLength;
+use crate::errors::{InvalidLength, SelfTestError};
 use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
 #[cfg(feature = "rand_core")]
 use rand_core::{CryptoRng, RngCore};
@@ -137,3 +182,22 @@ pub trait FromKey: Sized {
         key
     }
 }
+
+/// Trait for algorithms which provide a known-answer self-test.
+///
+/// Useful for hardware-backed or FFI implementations which should be checked
+/// on start up, e.g. to satisfy FIPS-style power-on self-tests.
+pub trait SelfTest {
+    /// Run the known-answer self-test.
+    fn self_test() -> Result<(), SelfTestError>;
+
+    /// Create new value from fixed size key after running the self-test.
+    #[inline]
+    fn new_checked(key: &GenericArray<u8, Self::KeySize>) -> Result<Self, SelfTestError>
+    where
+        Self: FromKey,
+    {
+        Self::self_test()?;
+        Ok(Self::new(key))
+    }
+}
@@ -1,5 +1,11 @@
-use crate::{BlockCipher, FromKeyNonce};
+use crate::{Block, BlockCipher, BlockDecrypt, FromKeyNonce, ParBlocks};
+#[cfg(feature = "zeroize")]
+use core::ops::{Deref, DerefMut};
 use generic_array::{ArrayLength, GenericArray};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 /// Trait for types which implement a block cipher [mode of operation][1].
 ///
@@ -13,6 +19,12 @@ pub trait BlockMode {
 ///
 /// This trait operates only on blocks, for convinient slice-based methods with padding
 /// see the [`BlockModeEncryptWrapper`][crate::BlockModeEncryptWrapper] type.
+///
+/// Unlike [`BlockEncrypt`][crate::BlockEncrypt], which describes a raw block cipher
+/// applied to each block independently, this trait describes a mode which
+/// carries chaining state between calls. Code which requires a mode
+/// specifically should use this trait as a bound and, if needed, combine it
+/// with [`BlockModeIvState`] to retrieve the final IV.
 pub trait BlockModeEncrypt: BlockMode {
     /// Encrypt blocks of data.
     fn encrypt_blocks(&mut self, blocks: &mut [GenericArray<u8, Self::BlockSize>]);
@@ -22,11 +34,63 @@ pub trait BlockModeEncrypt: BlockMode {
 ///
 /// This trait operates only on blocks, for convinient slice-based methods with padding
 /// see the [`BlockModeDecryptWrapper`][crate::BlockModeDecryptWrapper] type.
+///
+/// Unlike [`BlockDecrypt`][crate::BlockDecrypt], which describes a raw block cipher
+/// applied to each block independently, this trait describes a mode which
+/// carries chaining state between calls. Code which requires a mode
+/// specifically should use this trait as a bound and, if needed, combine it
+/// with [`BlockModeIvState`] to retrieve the final IV.
 pub trait BlockModeDecrypt: BlockMode {
     /// Decrypt blocks of data.
     fn decrypt_blocks(&mut self, blocks: &mut [GenericArray<u8, Self::BlockSize>]);
 }
 
+/// Trait for block ciphers which support parallel decryption of data
+/// encrypted in the CBC mode.
+///
+/// Each plaintext block of CBC depends only on two ciphertext blocks, so
+/// unlike encryption, decryption of all blocks can be done in parallel.
+/// The provided method is built on [`BlockDecrypt::decrypt_blocks`], so
+/// block ciphers with a parallel backend can opt into it with an empty
+/// impl:
+///
+/// ```
+/// # use cipher::{consts::{U4, U16}, Block, BlockCipher, BlockDecrypt};
+/// use cipher::BlockModeDecryptParallel;
+///
+/// # struct MyCipher;
+/// # impl BlockCipher for MyCipher { type BlockSize = U16; type ParBlocks = U4; }
+/// # impl BlockDecrypt for MyCipher { fn decrypt_block(&self, _: &mut Block<Self>) {} }
+/// impl BlockModeDecryptParallel for MyCipher {}
+/// ```
+pub trait BlockModeDecryptParallel: BlockDecrypt {
+    /// Decrypt CBC ciphertext `blocks` in place using the initialization
+    /// vector `iv`.
+    ///
+    /// Blocks are processed backwards in chunks of `ParBlocks` blocks,
+    /// starting from the end of the slice. This way the ciphertext block
+    /// preceding each chunk is still available for the XOR-chaining step,
+    /// so decryption does not require a copy of the whole ciphertext.
+    #[inline]
+    fn decrypt_backwards(&self, iv: &Block<Self>, blocks: &mut [Block<Self>]) {
+        let mut buf = ParBlocks::<Self>::default();
+        let mut end = blocks.len();
+        while end > 0 {
+            let start = end.saturating_sub(buf.len());
+            let buf = &mut buf[..end - start];
+            buf.clone_from_slice(&blocks[start..end]);
+            self.decrypt_blocks(buf);
+            for (i, block) in buf.iter_mut().enumerate().rev() {
+                let pos = start + i;
+                let prev = if pos == 0 { iv } else { &blocks[pos - 1] };
+                block.iter_mut().zip(prev.iter()).for_each(|(a, b)| *a ^= b);
+                blocks[pos].clone_from(block);
+            }
+            end = start;
+        }
+    }
+}
+
 /// Trait for a block mode, used to obtain the current state in the form of an IV
 /// that can initialize a BlockMode later and resume the original operation.
 ///
@@ -39,4 +103,160 @@ pub trait BlockModeIvState<C: BlockCipher>: FromKeyNonce {
     /// Returns the IV needed to process the following block. This value MUST
     /// NOT be exposed to attackers.
     fn iv_state(&self) -> GenericArray<u8, Self::NonceSize>;
+
+    /// Returns the IV needed to process the following block wrapped into
+    /// [`Zeroizing`], i.e. the returned value will be erased on drop.
+    ///
+    /// Note that the value returned by [`iv_state`][Self::iv_state] is moved
+    /// into the wrapper, so copies of it may still remain on the stack.
+    #[cfg(feature = "zeroize")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+    #[inline]
+    fn iv_state_zeroizing(&self) -> Zeroizing<GenericArray<u8, Self::NonceSize>> {
+        Zeroizing::new(self.iv_state())
+    }
+
+    /// Serialize the value returned by [`iv_state`][Self::iv_state] as
+    /// a fixed-length byte array.
+    ///
+    /// This value MUST NOT be exposed to attackers, i.e. the serialized
+    /// state has to be stored as securely as the key.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    #[inline]
+    fn serialize_iv_state<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.iv_state().serialize(serializer)
+    }
+
+    /// Deserialize IV state written by
+    /// [`serialize_iv_state`][Self::serialize_iv_state].
+    ///
+    /// Processing can be resumed by passing the result together with the key
+    /// to [`FromKeyNonce::new`] or to [`restore_iv_state`][Self::restore_iv_state].
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    #[inline]
+    fn deserialize_iv_state<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<GenericArray<u8, Self::NonceSize>, D::Error> {
+        GenericArray::deserialize(deserializer)
+    }
+
+    /// Restore chaining state from a value previously returned by
+    /// [`iv_state`][Self::iv_state], keeping the initialized block cipher.
+    ///
+    /// Processing continues exactly as it would have at the moment the
+    /// state was captured.
+    #[inline]
+    fn restore_iv_state(&mut self, iv: &GenericArray<u8, Self::NonceSize>)
+    where
+        Self: BlockModeReinit,
+    {
+        self.reinit(iv);
+    }
+}
+
+/// Trait for a block mode which can be reinitialized with a new IV while
+/// keeping the initialized block cipher (e.g. its expanded key schedule).
+///
+/// After calling [`reinit`][Self::reinit] the mode MUST behave exactly as a
+/// freshly created one with the same key and the new IV.
+pub trait BlockModeReinit: FromKeyNonce {
+    /// Reset chaining state and install the new `iv`.
+    fn reinit(&mut self, iv: &GenericArray<u8, Self::NonceSize>);
+}
+
+/// Wrapper around a block mode which zeroizes it on drop.
+///
+/// Modes opt into wiping their chaining state by implementing [`Zeroize`]
+/// and being constructed through this wrapper. With the `zeroize` feature
+/// enabled `GenericArray<u8, N>` implements [`Zeroize`], so an IV stored in
+/// a `GenericArray` field can be erased by calling `zeroize` on it.
+///
+/// The wrapper implements the block mode traits of the inner mode, so it
+/// can be used in place of it.
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub struct ZeroizingMode<M: Zeroize>(M);
+
+#[cfg(feature = "zeroize")]
+impl<M: Zeroize> ZeroizingMode<M> {
+    /// Wrap block mode.
+    pub fn from_mode(mode: M) -> Self {
+        Self(mode)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<M: Zeroize> Deref for ZeroizingMode<M> {
+    type Target = M;
+
+    fn deref(&self) -> &M {
+        &self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<M: Zeroize> DerefMut for ZeroizingMode<M> {
+    fn deref_mut(&mut self) -> &mut M {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<M: Zeroize> Drop for ZeroizingMode<M> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<M: Zeroize> ZeroizeOnDrop for ZeroizingMode<M> {}
+
+#[cfg(feature = "zeroize")]
+impl<M: Zeroize + FromKeyNonce> FromKeyNonce for ZeroizingMode<M> {
+    type KeySize = M::KeySize;
+    type NonceSize = M::NonceSize;
+
+    #[inline]
+    fn new(key: &GenericArray<u8, M::KeySize>, nonce: &GenericArray<u8, M::NonceSize>) -> Self {
+        Self(M::new(key, nonce))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<M: Zeroize + BlockMode> BlockMode for ZeroizingMode<M> {
+    type BlockSize = M::BlockSize;
+}
+
+#[cfg(feature = "zeroize")]
+impl<M: Zeroize + BlockModeEncrypt> BlockModeEncrypt for ZeroizingMode<M> {
+    #[inline]
+    fn encrypt_blocks(&mut self, blocks: &mut [GenericArray<u8, Self::BlockSize>]) {
+        self.0.encrypt_blocks(blocks);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<M: Zeroize + BlockModeDecrypt> BlockModeDecrypt for ZeroizingMode<M> {
+    #[inline]
+    fn decrypt_blocks(&mut self, blocks: &mut [GenericArray<u8, Self::BlockSize>]) {
+        self.0.decrypt_blocks(blocks);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: BlockCipher, M: Zeroize + BlockModeIvState<C>> BlockModeIvState<C> for ZeroizingMode<M> {
+    #[inline]
+    fn iv_state(&self) -> GenericArray<u8, Self::NonceSize> {
+        self.0.iv_state()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<M: Zeroize + BlockModeReinit> BlockModeReinit for ZeroizingMode<M> {
+    #[inline]
+    fn reinit(&mut self, iv: &GenericArray<u8, Self::NonceSize>) {
+        self.0.reinit(iv);
+    }
 }
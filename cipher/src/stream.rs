@@ -3,8 +3,12 @@
 //! See [RustCrypto/stream-ciphers](https://github.com/RustCrypto/stream-ciphers)
 //! for ciphers implementation.
 
-use crate::errors::{LoopError, OverflowError};
+use crate::errors::{CipherError, LoopError, OverflowError};
+#[cfg(feature = "bytes")]
+use bytes::BytesMut;
 use core::convert::{TryFrom, TryInto};
+#[cfg(feature = "std")]
+use std::io::IoSliceMut;
 
 /// Synchronous stream cipher core trait.
 pub trait StreamCipher {
@@ -27,6 +31,245 @@ pub trait StreamCipher {
     /// If end of the keystream will be achieved with the given data length,
     /// method will return `Err(LoopError)` without modifying provided `data`.
     fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<(), LoopError>;
+
+    /// Apply keystream to the data and return number of processed bytes.
+    ///
+    /// On success the returned value is equal to `data.len()`. If end of the
+    /// keystream will be achieved with the given data length, method will
+    /// return `Err(LoopError)` without modifying provided `data`.
+    #[inline]
+    fn apply_keystream_counted(&mut self, data: &mut [u8]) -> Result<usize, LoopError> {
+        self.try_apply_keystream(data)?;
+        Ok(data.len())
+    }
+
+    /// Write keystream into `buf`, overwriting its previous content.
+    ///
+    /// Keystream position gets advanced by `buf.len()` bytes.
+    ///
+    /// # Panics
+    /// If end of the keystream will be reached with the given buffer length,
+    /// method will panic.
+    #[inline]
+    fn write_keystream(&mut self, buf: &mut [u8]) {
+        self.try_write_keystream(buf).unwrap();
+    }
+
+    /// Write keystream into `buf`, overwriting its previous content, but
+    /// return an error if end of a keystream will be reached.
+    ///
+    /// If end of the keystream will be achieved with the given buffer length,
+    /// method will return `Err(LoopError)` and `buf` will be filled with zeros.
+    #[inline]
+    fn try_write_keystream(&mut self, buf: &mut [u8]) -> Result<(), LoopError> {
+        buf.iter_mut().for_each(|b| *b = 0);
+        self.try_apply_keystream(buf)
+    }
+
+    /// Apply keystream to the data split across several buffers.
+    ///
+    /// Buffers are processed in order as one contiguous stream, so
+    /// buffer boundaries do not have to be aligned with the cipher blocks.
+    ///
+    /// # Panics
+    /// If end of the keystream will be reached with the given data length,
+    /// method will panic.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn apply_keystream_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) {
+        self.try_apply_keystream_vectored(bufs).unwrap();
+    }
+
+    /// Apply keystream to the data split across several buffers, but
+    /// return an error if end of a keystream will be reached.
+    ///
+    /// Buffers are processed in order as one contiguous stream, so
+    /// buffer boundaries do not have to be aligned with the cipher blocks.
+    ///
+    /// If end of the keystream will be achieved while processing one of the
+    /// buffers, method will return `Err(LoopError)` leaving this and the
+    /// following buffers unmodified. Note that the preceding buffers will
+    /// have keystream applied to them.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    #[inline]
+    fn try_apply_keystream_vectored(
+        &mut self,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> Result<(), LoopError> {
+        for buf in bufs.iter_mut() {
+            self.try_apply_keystream(buf)?;
+        }
+        Ok(())
+    }
+
+    /// Advance keystream position by `n` bytes without applying keystream
+    /// to any data.
+    ///
+    /// For seekable ciphers it's more efficient to use
+    /// [`StreamCipherSeek::seek`] instead.
+    ///
+    /// # Panics
+    /// If end of the keystream will be reached with the given number of bytes,
+    /// method will panic.
+    #[inline]
+    fn discard_keystream(&mut self, n: usize) {
+        self.try_discard_keystream(n).unwrap();
+    }
+
+    /// Advance keystream position by `n` bytes without applying keystream
+    /// to any data, but return an error if end of a keystream will be reached.
+    ///
+    /// Keystream is generated and discarded in chunks, so on error the
+    /// keystream position may have been advanced by less than `n` bytes.
+    #[inline]
+    fn try_discard_keystream(&mut self, mut n: usize) -> Result<(), LoopError> {
+        let mut buf = [0u8; KEYSTREAM_BUF_SIZE];
+        while n != 0 {
+            let chunk_len = n.min(KEYSTREAM_BUF_SIZE);
+            self.try_apply_keystream(&mut buf[..chunk_len])?;
+            n -= chunk_len;
+        }
+        Ok(())
+    }
+
+    /// Apply keystream to data at `buf[in_offset..]` and write result to
+    /// `buf[out_offset..]`.
+    ///
+    /// Input and output regions may overlap. Number of processed bytes is
+    /// equal to `buf.len() - max(in_offset, out_offset)`; bytes outside of
+    /// the output region are left in an unspecified state.
+    ///
+    /// # Panics
+    /// If either offset is bigger than `buf.len()` or end of the keystream
+    /// will be reached with the given data length.
+    #[inline]
+    fn apply_keystream_overlapping(&mut self, buf: &mut [u8], in_offset: usize, out_offset: usize) {
+        self.try_apply_keystream_overlapping(buf, in_offset, out_offset)
+            .unwrap();
+    }
+
+    /// Apply keystream to data at `buf[in_offset..]` and write result to
+    /// `buf[out_offset..]`, but return an error if end of a keystream will
+    /// be reached.
+    ///
+    /// If end of the keystream is reached, `buf` is left unmodified.
+    ///
+    /// # Panics
+    /// If either offset is bigger than `buf.len()`.
+    #[inline]
+    fn try_apply_keystream_overlapping(
+        &mut self,
+        buf: &mut [u8],
+        in_offset: usize,
+        out_offset: usize,
+    ) -> Result<(), LoopError> {
+        assert!(in_offset <= buf.len() && out_offset <= buf.len());
+        let len = buf.len() - in_offset.max(out_offset);
+        let data = &mut buf[in_offset..][..len];
+        self.try_apply_keystream(data)?;
+        buf.copy_within(in_offset..in_offset + len, out_offset);
+        Ok(())
+    }
+
+    /// Convert stream cipher into an iterator over keystream bytes.
+    ///
+    /// The iterator returns `None` after end of the keystream was reached.
+    #[inline]
+    fn keystream_iter(self) -> KeystreamIter<Self>
+    where
+        Self: Sized,
+    {
+        KeystreamIter::new(self)
+    }
+
+    /// Apply keystream to `input` and write the result into `output`.
+    ///
+    /// # Panics
+    /// If `input` and `output` have different lengths, method will panic
+    /// without modifying `output`. If end of the keystream will be reached
+    /// with the given data length, method will panic after filling `output`
+    /// with zeros.
+    #[inline]
+    fn apply_keystream_b2b(&mut self, input: &[u8], output: &mut [u8]) {
+        self.try_apply_keystream_b2b(input, output).unwrap();
+    }
+
+    /// Apply keystream to `input` and write the result into `output`, but
+    /// return an error if end of a keystream will be reached.
+    ///
+    /// Returns [`CipherError::NotEqual`] without modifying `output` if `input`
+    /// and `output` have different lengths. If end of the keystream will be
+    /// achieved with the given data length, method will return
+    /// [`CipherError::Loop`] and `output` will be filled with zeros, i.e.
+    /// `input` never ends up in `output` unencrypted.
+    #[inline]
+    fn try_apply_keystream_b2b(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), CipherError> {
+        if input.len() != output.len() {
+            return Err(CipherError::NotEqual);
+        }
+        output.copy_from_slice(input);
+        self.try_apply_keystream(output).map_err(|err| {
+            output.iter_mut().for_each(|b| *b = 0);
+            err.into()
+        })
+    }
+
+    /// Apply keystream in place to the whole content of `buf`.
+    ///
+    /// # Panics
+    /// If end of the keystream will be reached with the given buffer length,
+    /// method will panic without modifying `buf`.
+    #[cfg(feature = "bytes")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+    #[inline]
+    fn apply_keystream_bytes(&mut self, buf: &mut BytesMut) {
+        self.apply_keystream(buf);
+    }
+
+    /// Apply keystream in place to the first `at` bytes of `buf` and split
+    /// them off without copying.
+    ///
+    /// Returns the processed prefix, while `buf` retains the remaining
+    /// bytes `[at, len)`.
+    ///
+    /// # Panics
+    /// If `at` is bigger than `buf.len()` or if end of the keystream will be
+    /// reached with the given length, method will panic without modifying
+    /// `buf`.
+    #[cfg(feature = "bytes")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+    #[inline]
+    fn apply_keystream_split_to(&mut self, buf: &mut BytesMut, at: usize) -> BytesMut {
+        self.try_apply_keystream_split_to(buf, at).unwrap()
+    }
+
+    /// Apply keystream in place to the first `at` bytes of `buf` and split
+    /// them off without copying, but return an error if end of a keystream
+    /// will be reached.
+    ///
+    /// If end of the keystream will be achieved with the given length, method
+    /// will return `Err(LoopError)` without modifying `buf`.
+    ///
+    /// # Panics
+    /// If `at` is bigger than `buf.len()`.
+    #[cfg(feature = "bytes")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+    #[inline]
+    fn try_apply_keystream_split_to(
+        &mut self,
+        buf: &mut BytesMut,
+        at: usize,
+    ) -> Result<BytesMut, LoopError> {
+        assert!(at <= buf.len(), "split point is out of bounds");
+        self.try_apply_keystream(&mut buf[..at])?;
+        Ok(buf.split_to(at))
+    }
 }
 
 /// Trait for seekable stream ciphers.
@@ -61,6 +304,238 @@ pub trait StreamCipherSeek {
     fn seek<T: SeekNum>(&mut self, pos: T) {
         self.try_seek(pos).unwrap()
     }
+
+    /// Write upcoming keystream into `buf` without advancing the keystream
+    /// position.
+    ///
+    /// # Panics
+    /// If end of the keystream will be reached with the given buffer length.
+    #[inline]
+    fn peek_keystream(&mut self, buf: &mut [u8])
+    where
+        Self: StreamCipher,
+    {
+        self.try_peek_keystream(buf).unwrap()
+    }
+
+    /// Write upcoming keystream into `buf` without advancing the keystream
+    /// position, but return an error if end of a keystream will be reached.
+    ///
+    /// On error `buf` will be filled with zeros.
+    ///
+    /// The default implementation writes keystream using
+    /// [`StreamCipher::try_write_keystream`] and seeks back to the original
+    /// position. Wrappers which observe keystream application, e.g. to
+    /// track used positions, must override this method and forward it to
+    /// the inner cipher, since peeking does not consume any keystream.
+    #[inline]
+    fn try_peek_keystream(&mut self, buf: &mut [u8]) -> Result<(), LoopError>
+    where
+        Self: StreamCipher,
+    {
+        let pos: u128 = self.try_current_pos()?;
+        self.try_write_keystream(buf)?;
+        self.try_seek(pos)
+    }
+}
+
+/// Trait for querying capabilities of a stream cipher.
+///
+/// Allows generic code to detect optional functionality at runtime. It's
+/// separate from [`StreamCipher`], since associated constants would make
+/// `dyn StreamCipher` impossible.
+pub trait StreamCipherCapabilities: StreamCipher {
+    /// Whether the cipher implements [`StreamCipherSeek`].
+    ///
+    /// Seekable ciphers should override it with `true`.
+    const IS_SEEKABLE: bool = false;
+}
+
+impl<C: StreamCipherCapabilities> StreamCipherCapabilities for &mut C {
+    const IS_SEEKABLE: bool = C::IS_SEEKABLE;
+}
+
+/// Wrapper around a stream cipher which generates `N` bytes of keystream
+/// ahead of time and applies keystream from the internal buffer.
+///
+/// After the buffer gets drained, keystream for the rest of the data is
+/// applied by the inner cipher and the buffer is filled again. If end of the
+/// keystream is too close to fill the whole buffer, it stays empty and
+/// keystream is applied by the inner cipher directly. Seeking discards the
+/// buffered keystream.
+pub struct PrefetchStream<C: StreamCipher, const N: usize> {
+    cipher: C,
+    buf: [u8; N],
+    pos: usize,
+}
+
+impl<C: StreamCipher, const N: usize> PrefetchStream<C, N> {
+    /// Create new wrapper around the stream cipher.
+    ///
+    /// Keystream is not generated until the first use.
+    pub fn new(cipher: C) -> Self {
+        Self {
+            cipher,
+            buf: [0; N],
+            pos: N,
+        }
+    }
+
+    /// Return the inner stream cipher.
+    ///
+    /// Note that position of the inner cipher includes buffered keystream.
+    pub fn into_inner(self) -> C {
+        self.cipher
+    }
+}
+
+impl<C: StreamCipher, const N: usize> StreamCipher for PrefetchStream<C, N> {
+    fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<(), LoopError> {
+        let buffered = &self.buf[self.pos..];
+        if data.len() <= buffered.len() {
+            data.iter_mut().zip(buffered).for_each(|(a, b)| *a ^= b);
+            self.pos += data.len();
+            return Ok(());
+        }
+
+        let (head, tail) = data.split_at_mut(buffered.len());
+        self.cipher.try_apply_keystream(tail)?;
+        head.iter_mut().zip(buffered).for_each(|(a, b)| *a ^= b);
+        self.pos = match self.cipher.try_write_keystream(&mut self.buf) {
+            Ok(()) => 0,
+            Err(_) => N,
+        };
+        Ok(())
+    }
+}
+
+impl<C: StreamCipherCapabilities, const N: usize> StreamCipherCapabilities
+    for PrefetchStream<C, N>
+{
+    const IS_SEEKABLE: bool = C::IS_SEEKABLE;
+}
+
+impl<C: StreamCipher + StreamCipherSeek, const N: usize> StreamCipherSeek for PrefetchStream<C, N> {
+    fn try_current_pos<T: SeekNum>(&self) -> Result<T, OverflowError> {
+        let pos: u128 = self.cipher.try_current_pos()?;
+        let buffered = (N - self.pos) as u128;
+        T::try_from(pos - buffered).map_err(|_| OverflowError)
+    }
+
+    fn try_seek<T: SeekNum>(&mut self, pos: T) -> Result<(), LoopError> {
+        self.cipher.try_seek(pos)?;
+        self.pos = N;
+        Ok(())
+    }
+}
+
+/// Wrapper around a seekable stream cipher which detects keystream reuse.
+///
+/// In debug builds the wrapper tracks the highest keystream position used so
+/// far and panics if keystream is applied at a lower position, e.g. after
+/// seeking backwards. Note that positions skipped by seeking forward are
+/// also considered used. In release builds no tracking is performed and the
+/// wrapper simply forwards all calls to the inner cipher.
+///
+/// This wrapper is intended for catching misuse during development and
+/// it should not be used for decryption, which may legitimately re-process
+/// data at lower positions.
+pub struct NonceMisuseGuard<C: StreamCipher + StreamCipherSeek> {
+    cipher: C,
+    #[cfg(debug_assertions)]
+    used: u128,
+}
+
+impl<C: StreamCipher + StreamCipherSeek> NonceMisuseGuard<C> {
+    /// Create new guard around the stream cipher.
+    ///
+    /// Keystream positions before the current position of `cipher` are
+    /// considered used.
+    pub fn new(cipher: C) -> Self {
+        Self {
+            #[cfg(debug_assertions)]
+            used: cipher.current_pos(),
+            cipher,
+        }
+    }
+
+    /// Return the inner stream cipher.
+    pub fn into_inner(self) -> C {
+        self.cipher
+    }
+}
+
+impl<C: StreamCipher + StreamCipherSeek> StreamCipher for NonceMisuseGuard<C> {
+    #[inline]
+    fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<(), LoopError> {
+        #[cfg(debug_assertions)]
+        {
+            let pos: u128 = self.cipher.current_pos();
+            assert!(
+                data.is_empty() || pos >= self.used,
+                "keystream reuse detected at position {}",
+                pos,
+            );
+        }
+        self.cipher.try_apply_keystream(data)?;
+        #[cfg(debug_assertions)]
+        {
+            self.used = self.used.max(self.cipher.current_pos());
+        }
+        Ok(())
+    }
+}
+
+impl<C: StreamCipher + StreamCipherSeek> StreamCipherCapabilities for NonceMisuseGuard<C> {
+    const IS_SEEKABLE: bool = true;
+}
+
+impl<C: StreamCipher + StreamCipherSeek> StreamCipherSeek for NonceMisuseGuard<C> {
+    #[inline]
+    fn try_current_pos<T: SeekNum>(&self) -> Result<T, OverflowError> {
+        self.cipher.try_current_pos()
+    }
+
+    #[inline]
+    fn try_seek<T: SeekNum>(&mut self, pos: T) -> Result<(), LoopError> {
+        self.cipher.try_seek(pos)
+    }
+
+    #[inline]
+    fn try_peek_keystream(&mut self, buf: &mut [u8]) -> Result<(), LoopError> {
+        self.cipher.try_peek_keystream(buf)
+    }
+}
+
+/// Trait for stream ciphers which can save their state and later restore it,
+/// e.g. to rewind after a failed parse.
+///
+/// This trait is automatically implemented for all stream ciphers which
+/// implement [`Clone`], in which case the checkpoint is a full copy of the
+/// cipher state.
+pub trait StreamCipherCheckpoint: StreamCipher {
+    /// Saved cipher state.
+    type Checkpoint;
+
+    /// Save current cipher state.
+    fn checkpoint(&self) -> Self::Checkpoint;
+
+    /// Restore cipher state saved by [`checkpoint`][Self::checkpoint].
+    fn restore(&mut self, checkpoint: Self::Checkpoint);
+}
+
+impl<C: StreamCipher + Clone> StreamCipherCheckpoint for C {
+    type Checkpoint = C;
+
+    #[inline]
+    fn checkpoint(&self) -> C {
+        self.clone()
+    }
+
+    #[inline]
+    fn restore(&mut self, checkpoint: C) {
+        *self = checkpoint;
+    }
 }
 
 /// Asynchronous stream cipher core trait.
@@ -82,13 +557,159 @@ impl<C: StreamCipher> StreamCipher for &mut C {
     fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<(), LoopError> {
         C::try_apply_keystream(self, data)
     }
+
+    #[inline]
+    fn apply_keystream_counted(&mut self, data: &mut [u8]) -> Result<usize, LoopError> {
+        C::apply_keystream_counted(self, data)
+    }
+
+    #[inline]
+    fn write_keystream(&mut self, buf: &mut [u8]) {
+        C::write_keystream(self, buf);
+    }
+
+    #[inline]
+    fn try_write_keystream(&mut self, buf: &mut [u8]) -> Result<(), LoopError> {
+        C::try_write_keystream(self, buf)
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn apply_keystream_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) {
+        C::apply_keystream_vectored(self, bufs);
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn try_apply_keystream_vectored(
+        &mut self,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> Result<(), LoopError> {
+        C::try_apply_keystream_vectored(self, bufs)
+    }
+
+    #[inline]
+    fn discard_keystream(&mut self, n: usize) {
+        C::discard_keystream(self, n);
+    }
+
+    #[inline]
+    fn try_discard_keystream(&mut self, n: usize) -> Result<(), LoopError> {
+        C::try_discard_keystream(self, n)
+    }
+
+    #[inline]
+    fn apply_keystream_overlapping(&mut self, buf: &mut [u8], in_offset: usize, out_offset: usize) {
+        C::apply_keystream_overlapping(self, buf, in_offset, out_offset);
+    }
+
+    #[inline]
+    fn try_apply_keystream_overlapping(
+        &mut self,
+        buf: &mut [u8],
+        in_offset: usize,
+        out_offset: usize,
+    ) -> Result<(), LoopError> {
+        C::try_apply_keystream_overlapping(self, buf, in_offset, out_offset)
+    }
+
+    #[inline]
+    fn apply_keystream_b2b(&mut self, input: &[u8], output: &mut [u8]) {
+        C::apply_keystream_b2b(self, input, output);
+    }
+
+    #[inline]
+    fn try_apply_keystream_b2b(
+        &mut self,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<(), CipherError> {
+        C::try_apply_keystream_b2b(self, input, output)
+    }
+
+    #[cfg(feature = "bytes")]
+    #[inline]
+    fn apply_keystream_bytes(&mut self, buf: &mut BytesMut) {
+        C::apply_keystream_bytes(self, buf);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[inline]
+    fn apply_keystream_split_to(&mut self, buf: &mut BytesMut, at: usize) -> BytesMut {
+        C::apply_keystream_split_to(self, buf, at)
+    }
+
+    #[cfg(feature = "bytes")]
+    #[inline]
+    fn try_apply_keystream_split_to(
+        &mut self,
+        buf: &mut BytesMut,
+        at: usize,
+    ) -> Result<BytesMut, LoopError> {
+        C::try_apply_keystream_split_to(self, buf, at)
+    }
+}
+
+/// Size of the buffer used by [`KeystreamIter`] and for discarding keystream.
+const KEYSTREAM_BUF_SIZE: usize = 64;
+
+/// Iterator over keystream bytes of a stream cipher.
+///
+/// The iterator generates keystream in chunks, so the inner cipher may be
+/// ahead of the last returned byte by up to 64 bytes.
+///
+/// This struct is created by the [`StreamCipher::keystream_iter`] method.
+pub struct KeystreamIter<C: StreamCipher> {
+    cipher: C,
+    buf: [u8; KEYSTREAM_BUF_SIZE],
+    pos: usize,
+    len: usize,
+}
+
+impl<C: StreamCipher> KeystreamIter<C> {
+    fn new(cipher: C) -> Self {
+        Self {
+            cipher,
+            buf: [0; KEYSTREAM_BUF_SIZE],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Return the inner stream cipher.
+    pub fn into_inner(self) -> C {
+        self.cipher
+    }
+}
+
+impl<C: StreamCipher> Iterator for KeystreamIter<C> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        if self.pos == self.len {
+            // if keystream has less than a full buffer left,
+            // fall back to generating it byte by byte
+            self.len = if self.cipher.try_write_keystream(&mut self.buf).is_ok() {
+                KEYSTREAM_BUF_SIZE
+            } else if self.cipher.try_write_keystream(&mut self.buf[..1]).is_ok() {
+                1
+            } else {
+                return None;
+            };
+            self.pos = 0;
+        }
+        let b = self.buf[self.pos];
+        self.pos += 1;
+        Some(b)
+    }
 }
 
 /// Trait implemented for numeric types which can be used with the
 /// [`StreamCipherSeek`] trait.
 ///
 /// This trait is implemented for primitive numeric types, i.e. `i/u8`,
-/// `u16`, `u32`, `u64`, `u128`, `usize`, and `i32`. It is not intended
+/// `i/u16`, `i/u32`, `i/u64`, `i/u128`, and `i/usize`. It is not intended
 /// to be implemented in third-party crates.
 #[rustfmt::skip]
 pub trait SeekNum:
@@ -115,15 +736,31 @@ macro_rules! impl_seek_num {
             impl SeekNum for $t {
                 fn from_block_byte<T: TryInto<Self>>(block: T, byte: u8, bs: u8) -> Result<Self, OverflowError> {
                     debug_assert!(byte < bs);
-                    let block = block.try_into().map_err(|_| OverflowError)?;
-                    let pos = block.checked_mul(bs as Self).ok_or(OverflowError)? + (byte as Self);
-                    Ok(pos)
+                    let block: Self = block.try_into().map_err(|_| OverflowError)?;
+                    let byte = Self::try_from(byte).map_err(|_| OverflowError)?;
+                    if block == 0 {
+                        return Ok(byte);
+                    }
+                    // block size may not be representable by small signed types
+                    let bs = Self::try_from(bs).map_err(|_| OverflowError)?;
+                    block
+                        .checked_mul(bs)
+                        .and_then(|pos| pos.checked_add(byte))
+                        .ok_or(OverflowError)
                 }
 
+                #[allow(unused_comparisons)]
                 fn to_block_byte<T: TryFrom<Self>>(self, bs: u8) -> Result<(T, u8), OverflowError> {
-                    let bs = bs as Self;
-                    let byte = self % bs;
-                    let block = T::try_from(self/bs).map_err(|_| OverflowError)?;
+                    if self < 0 {
+                        return Err(OverflowError);
+                    }
+                    // if block size is not representable by `Self`, it's
+                    // bigger than any position, i.e. position is in block 0
+                    let (block, byte) = match Self::try_from(bs) {
+                        Ok(bs) => (self / bs, self % bs),
+                        Err(_) => (0, self),
+                    };
+                    let block = T::try_from(block).map_err(|_| OverflowError)?;
                     Ok((block, byte as u8))
                 }
             }
@@ -131,4 +768,4 @@ macro_rules! impl_seek_num {
     };
 }
 
-impl_seek_num! { u8 u16 u32 u64 u128 usize i32 }
+impl_seek_num! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
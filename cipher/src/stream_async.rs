@@ -0,0 +1,139 @@
+//! [`futures`](https://docs.rs/futures) adapters for stream ciphers.
+
+use crate::{errors::LoopError, StreamCipher};
+use bytes::{Bytes, BytesMut};
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::Stream;
+use futures_sink::Sink;
+
+/// Apply keystream to `chunk`, in place if it's the only reference to its
+/// buffer, otherwise to a copy of it.
+fn apply_keystream_chunk<C: StreamCipher>(
+    cipher: &mut C,
+    chunk: Bytes,
+) -> Result<Bytes, LoopError> {
+    let mut buf = BytesMut::from(chunk);
+    cipher.try_apply_keystream(&mut buf)?;
+    Ok(buf.freeze())
+}
+
+/// Sink which applies keystream to all chunks sent into it and passes
+/// the result to the inner sink.
+///
+/// Stream ciphers process data of arbitrary length, so chunks are passed
+/// to the inner sink one-to-one without any buffering and their boundaries
+/// do not have to be aligned with the cipher blocks. Backpressure of the
+/// inner sink is propagated by [`poll_ready`][Sink::poll_ready].
+///
+/// If end of the keystream is reached, the chunk is dropped and
+/// [`LoopError`] converted into the inner sink error is returned.
+///
+/// The inner sink has to be [`Unpin`], wrap it into [`Box::pin`] otherwise.
+///
+/// [`Box::pin`]: https://doc.rust-lang.org/std/boxed/struct.Box.html#method.pin
+pub struct StreamCipherSink<C: StreamCipher, S: Sink<Bytes>> {
+    cipher: C,
+    inner: S,
+}
+
+impl<C: StreamCipher, S: Sink<Bytes>> StreamCipherSink<C, S> {
+    /// Create new sink from stream cipher and inner sink.
+    pub fn new(cipher: C, inner: S) -> Self {
+        Self { cipher, inner }
+    }
+
+    /// Get reference to the inner sink.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Return the stream cipher and the inner sink.
+    pub fn into_inner(self) -> (C, S) {
+        (self.cipher, self.inner)
+    }
+}
+
+impl<C, S> Sink<Bytes> for StreamCipherSink<C, S>
+where
+    C: StreamCipher + Unpin,
+    S: Sink<Bytes> + Unpin,
+    S::Error: From<LoopError>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Bytes) -> Result<(), S::Error> {
+        let this = self.get_mut();
+        let item = apply_keystream_chunk(&mut this.cipher, item)?;
+        Pin::new(&mut this.inner).start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+/// Stream which applies keystream to all chunks returned by the inner stream.
+///
+/// Chunks are returned one-to-one as they are received from the inner
+/// stream, so their boundaries do not have to be aligned with the cipher
+/// blocks.
+///
+/// If end of the keystream is reached, the chunk is dropped and
+/// [`LoopError`] converted into the inner stream error is returned.
+///
+/// The inner stream has to be [`Unpin`], wrap it into [`Box::pin`] otherwise.
+///
+/// [`Box::pin`]: https://doc.rust-lang.org/std/boxed/struct.Box.html#method.pin
+pub struct StreamCipherStream<C: StreamCipher, S: Stream> {
+    cipher: C,
+    inner: S,
+}
+
+impl<C: StreamCipher, S: Stream> StreamCipherStream<C, S> {
+    /// Create new stream from stream cipher and inner stream.
+    pub fn new(cipher: C, inner: S) -> Self {
+        Self { cipher, inner }
+    }
+
+    /// Get reference to the inner stream.
+    pub fn get_ref(&self) -> &S {
+        &self.inner
+    }
+
+    /// Return the stream cipher and the inner stream.
+    pub fn into_inner(self) -> (C, S) {
+        (self.cipher, self.inner)
+    }
+}
+
+impl<C, S, E> Stream for StreamCipherStream<C, S>
+where
+    C: StreamCipher + Unpin,
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+    E: From<LoopError>,
+{
+    type Item = Result<Bytes, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let item = match Pin::new(&mut this.inner).poll_next(cx) {
+            Poll::Ready(Some(Ok(item))) => item,
+            res => return res,
+        };
+        let res = apply_keystream_chunk(&mut this.cipher, item).map_err(E::from);
+        Poll::Ready(Some(res))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
@@ -0,0 +1,46 @@
+//! [`core::fmt`] adapter for stream ciphers.
+
+use crate::StreamCipher;
+use core::fmt;
+
+/// Size of the stack buffer used for applying keystream to written text.
+const BUF_SIZE: usize = 64;
+
+/// [`fmt::Write`] sink which applies keystream to the UTF-8 bytes of all
+/// text written into it and passes the result to the inner callback.
+///
+/// Since [`fmt::Write`] can not return arbitrary errors, reaching end of the
+/// keystream results in [`fmt::Error`]. In this case text is passed to the
+/// callback only up to the chunk which caused the error.
+pub struct StreamCipherFmtWriter<C: StreamCipher, F: FnMut(&[u8])> {
+    cipher: C,
+    sink: F,
+}
+
+impl<C: StreamCipher, F: FnMut(&[u8])> StreamCipherFmtWriter<C, F> {
+    /// Create new writer from stream cipher and callback which receives
+    /// the resulting ciphertext.
+    pub fn new(cipher: C, sink: F) -> Self {
+        Self { cipher, sink }
+    }
+
+    /// Return the stream cipher and the callback.
+    pub fn into_inner(self) -> (C, F) {
+        (self.cipher, self.sink)
+    }
+}
+
+impl<C: StreamCipher, F: FnMut(&[u8])> fmt::Write for StreamCipherFmtWriter<C, F> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut buf = [0u8; BUF_SIZE];
+        for chunk in s.as_bytes().chunks(BUF_SIZE) {
+            let buf = &mut buf[..chunk.len()];
+            buf.copy_from_slice(chunk);
+            self.cipher
+                .try_apply_keystream(buf)
+                .map_err(|_| fmt::Error)?;
+            (self.sink)(buf);
+        }
+        Ok(())
+    }
+}
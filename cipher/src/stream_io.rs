@@ -0,0 +1,114 @@
+//! [`std::io`] adapters for stream ciphers.
+
+use crate::StreamCipher;
+use std::io;
+
+/// Size of the stack buffer used for applying keystream to written data.
+const BUF_SIZE: usize = 512;
+
+/// Writer which applies keystream to all data written into it and passes
+/// the result to the inner writer.
+///
+/// Keystream is applied before data is passed to the inner writer, so if the
+/// inner writer returns an error, it's unknown how much of the encrypted data
+/// was written and the cipher state can not be kept consistent with the
+/// inner writer. In this case the writer becomes poisoned: all subsequent
+/// writes and [`finish`][Self::finish] return an error.
+pub struct StreamCipherWriter<C: StreamCipher, W: io::Write> {
+    cipher: C,
+    inner: W,
+    poisoned: bool,
+}
+
+impl<C: StreamCipher, W: io::Write> StreamCipherWriter<C, W> {
+    /// Create new writer from stream cipher and inner writer.
+    pub fn new(cipher: C, inner: W) -> Self {
+        Self {
+            cipher,
+            inner,
+            poisoned: false,
+        }
+    }
+
+    /// Get reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Flush the inner writer and return the stream cipher and the inner writer.
+    ///
+    /// Returns an error if the writer is poisoned.
+    pub fn finish(mut self) -> io::Result<(C, W)> {
+        self.check_poisoned()?;
+        self.inner.flush()?;
+        Ok((self.cipher, self.inner))
+    }
+
+    fn check_poisoned(&self) -> io::Result<()> {
+        if self.poisoned {
+            Err(io::Error::other(
+                "stream cipher writer is poisoned by an inner writer error",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<C: StreamCipher, W: io::Write> io::Write for StreamCipherWriter<C, W> {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        self.check_poisoned()?;
+        let n = data.len().min(BUF_SIZE);
+        let mut buf = [0u8; BUF_SIZE];
+        let buf = &mut buf[..n];
+        buf.copy_from_slice(&data[..n]);
+        self.cipher
+            .try_apply_keystream(buf)
+            .map_err(io::Error::other)?;
+        if let Err(err) = self.inner.write_all(buf) {
+            self.poisoned = true;
+            return Err(err);
+        }
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Reader which applies keystream to all data read from the inner reader.
+///
+/// Keystream is applied only to bytes returned by the inner reader, so
+/// position of the stream cipher always matches the number of bytes read.
+pub struct StreamCipherReader<C: StreamCipher, R: io::Read> {
+    cipher: C,
+    inner: R,
+}
+
+impl<C: StreamCipher, R: io::Read> StreamCipherReader<C, R> {
+    /// Create new reader from stream cipher and inner reader.
+    pub fn new(cipher: C, inner: R) -> Self {
+        Self { cipher, inner }
+    }
+
+    /// Get reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Return the stream cipher and the inner reader.
+    pub fn into_inner(self) -> (C, R) {
+        (self.cipher, self.inner)
+    }
+}
+
+impl<C: StreamCipher, R: io::Read> io::Read for StreamCipherReader<C, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.cipher
+            .try_apply_keystream(&mut buf[..n])
+            .map_err(io::Error::other)?;
+        Ok(n)
+    }
+}
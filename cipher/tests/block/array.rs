@@ -0,0 +1,16 @@
+use cipher::{Block, BlockDecrypt, BlockEncrypt};
+
+use crate::common::DummyCipher;
+
+#[test]
+fn array() {
+    let pt: [u8; 16] = core::array::from_fn(|i| i as u8);
+    let mut expected = Block::<DummyCipher>::from(pt);
+    DummyCipher.encrypt_block(&mut expected);
+
+    let mut buf = pt;
+    DummyCipher.encrypt_array(&mut buf);
+    assert_eq!(buf[..], expected[..]);
+    DummyCipher.decrypt_array(&mut buf);
+    assert_eq!(buf, pt);
+}
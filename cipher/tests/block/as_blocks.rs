@@ -0,0 +1,61 @@
+use cipher::errors::InvalidLength;
+use cipher::{as_blocks, as_blocks_mut, block_from_mut_slice, block_from_slice, BlockEncrypt};
+
+use crate::common::{dummy_encrypt, DummyCipher};
+
+const BS: usize = 16;
+
+#[test]
+fn aligned() {
+    let buf: Vec<u8> = (0..3 * BS as u8).collect();
+    let blocks = as_blocks::<DummyCipher>(&buf).unwrap();
+    assert_eq!(blocks.len(), 3);
+    for (block, chunk) in blocks.zip(buf.chunks(BS)) {
+        assert_eq!(block.as_slice(), chunk);
+    }
+    assert_eq!(as_blocks::<DummyCipher>(&[]).unwrap().len(), 0);
+
+    let mut expected = buf.clone();
+    expected.chunks_mut(BS).for_each(dummy_encrypt);
+    let mut buf = buf;
+    for block in as_blocks_mut::<DummyCipher>(&mut buf).unwrap() {
+        DummyCipher.encrypt_block(block);
+    }
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn misaligned() {
+    let mut buf = [0u8; 3 * BS];
+    for len in [1, BS - 1, BS + 1, 3 * BS - 1] {
+        assert_eq!(
+            as_blocks::<DummyCipher>(&buf[..len]).err(),
+            Some(InvalidLength)
+        );
+        assert!(as_blocks_mut::<DummyCipher>(&mut buf[..len]).is_err());
+    }
+}
+
+#[test]
+fn from_slice() {
+    let mut buf: Vec<u8> = (0..2 * BS as u8).collect();
+
+    let block = block_from_slice::<DummyCipher>(&buf[..BS]).unwrap();
+    assert_eq!(block.as_slice(), &buf[..BS]);
+    let mut expected = buf[BS..].to_vec();
+    dummy_encrypt(&mut expected);
+    let block = block_from_mut_slice::<DummyCipher>(&mut buf[BS..]).unwrap();
+    DummyCipher.encrypt_block(block);
+    assert_eq!(&buf[BS..], &expected[..]);
+
+    for len in [0, BS - 1, BS + 1, 2 * BS] {
+        assert_eq!(
+            block_from_slice::<DummyCipher>(&buf[..len]),
+            Err(InvalidLength)
+        );
+        assert_eq!(
+            block_from_mut_slice::<DummyCipher>(&mut buf[..len]),
+            Err(InvalidLength)
+        );
+    }
+}
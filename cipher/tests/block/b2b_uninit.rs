@@ -0,0 +1,28 @@
+use cipher::{Block, BlockDecrypt, BlockEncrypt};
+
+use crate::common::DummyCipher;
+
+#[test]
+fn b2b_uninit() {
+    let pt: Vec<Block<DummyCipher>> = (0..7u8)
+        .map(|i| Block::<DummyCipher>::from([i; 16]))
+        .collect();
+    let mut expected = pt.clone();
+    DummyCipher.encrypt_blocks(&mut expected);
+
+    let mut ct: Vec<Block<DummyCipher>> = Vec::with_capacity(pt.len());
+    let out = &mut ct.spare_capacity_mut()[..pt.len()];
+    let res = DummyCipher.encrypt_blocks_b2b_uninit(&pt, out).unwrap();
+    assert_eq!(res, &expected[..]);
+
+    let mut dec: Vec<Block<DummyCipher>> = Vec::with_capacity(pt.len());
+    let out = &mut dec.spare_capacity_mut()[..pt.len()];
+    let res = DummyCipher
+        .decrypt_blocks_b2b_uninit(&expected, out)
+        .unwrap();
+    assert_eq!(res, &pt[..]);
+
+    let mut buf: Vec<Block<DummyCipher>> = Vec::with_capacity(pt.len() + 1);
+    let out = &mut buf.spare_capacity_mut()[..pt.len() + 1];
+    assert!(DummyCipher.encrypt_blocks_b2b_uninit(&pt, out).is_err());
+}
@@ -0,0 +1,29 @@
+use cipher::{Block, BlockDecrypt, BlockDecryptMut, BlockEncrypt, BlockEncryptMut};
+
+use crate::common::DummyCipher;
+
+#[test]
+fn block_ret() {
+    let pt: Vec<Block<DummyCipher>> = (0..4u8)
+        .map(|i| Block::<DummyCipher>::from([i; 16]))
+        .collect();
+    let mut expected = pt.clone();
+    DummyCipher.encrypt_blocks(&mut expected);
+
+    let ct: Vec<_> = pt
+        .iter()
+        .map(|b| DummyCipher.encrypt_block_ret(b))
+        .collect();
+    assert_eq!(ct, expected);
+    let res: Vec<_> = ct
+        .iter()
+        .map(|b| DummyCipher.decrypt_block_ret(b))
+        .collect();
+    assert_eq!(res, pt);
+
+    let mut cipher = DummyCipher;
+    let ct: Vec<_> = pt.iter().map(|b| cipher.encrypt_block_ret_mut(b)).collect();
+    assert_eq!(ct, expected);
+    let res: Vec<_> = ct.iter().map(|b| cipher.decrypt_block_ret_mut(b)).collect();
+    assert_eq!(res, pt);
+}
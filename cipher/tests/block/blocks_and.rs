@@ -0,0 +1,18 @@
+use cipher::{Block, BlockEncrypt};
+
+use crate::common::DummyParCipher;
+
+#[test]
+fn blocks_and() {
+    for n in 0..10u8 {
+        let pt: Vec<Block<DummyParCipher>> = (0..n).map(|i| [i; 16].into()).collect();
+        let mut expected = pt.clone();
+        DummyParCipher.encrypt_blocks(&mut expected);
+
+        let mut seen = Vec::new();
+        let mut buf = pt.clone();
+        DummyParCipher.encrypt_blocks_and(&mut buf, |block| seen.push(*block));
+        assert_eq!(buf, expected);
+        assert_eq!(seen, expected);
+    }
+}
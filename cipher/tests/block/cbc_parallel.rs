@@ -0,0 +1,53 @@
+use cipher::{Block, BlockDecrypt, BlockEncrypt, BlockModeDecryptParallel, FromKey};
+
+use crate::common::DummyKeyedCipher;
+
+impl BlockModeDecryptParallel for DummyKeyedCipher {}
+
+type B = Block<DummyKeyedCipher>;
+
+fn xor(a: &mut B, b: &B) {
+    a.iter_mut().zip(b.iter()).for_each(|(a, b)| *a ^= b);
+}
+
+fn cbc_encrypt(cipher: &DummyKeyedCipher, iv: &B, blocks: &mut [B]) {
+    let mut prev = *iv;
+    for block in blocks {
+        xor(block, &prev);
+        cipher.encrypt_block(block);
+        prev = *block;
+    }
+}
+
+fn cbc_decrypt(cipher: &DummyKeyedCipher, iv: &B, blocks: &mut [B]) {
+    let mut prev = *iv;
+    for block in blocks {
+        let ct = *block;
+        cipher.decrypt_block(block);
+        xor(block, &prev);
+        prev = ct;
+    }
+}
+
+#[test]
+fn decrypt_backwards() {
+    let cipher = DummyKeyedCipher::new(&Default::default());
+    let iv = B::from([0x5A; 16]);
+
+    // cover empty input, tails shorter than `ParBlocks` and several chunks
+    for n in 0..14 {
+        let pt: Vec<B> = (0..n)
+            .map(|i| B::clone_from_slice(&[(i * 16) as u8; 16]))
+            .collect();
+        let mut ct = pt.clone();
+        cbc_encrypt(&cipher, &iv, &mut ct);
+
+        let mut expected = ct.clone();
+        cbc_decrypt(&cipher, &iv, &mut expected);
+        assert_eq!(expected, pt, "blocks: {}", n);
+
+        let mut res = ct.clone();
+        cipher.decrypt_backwards(&iv, &mut res);
+        assert_eq!(res, pt, "blocks: {}", n);
+    }
+}
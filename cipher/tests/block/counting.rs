@@ -0,0 +1,47 @@
+use cipher::{Block, BlockDecrypt, BlockEncrypt, CountingCipher, FromKey};
+
+use crate::common::{DummyCipher, DummyKeyedCipher};
+
+fn cbc_encrypt<C: BlockEncrypt>(cipher: &C, iv: &Block<C>, blocks: &mut [Block<C>]) {
+    let mut prev = iv.clone();
+    for block in blocks {
+        block.iter_mut().zip(prev.iter()).for_each(|(a, b)| *a ^= b);
+        cipher.encrypt_block(block);
+        prev = block.clone();
+    }
+}
+
+#[test]
+fn counting() {
+    let cipher = CountingCipher::from_cipher(DummyCipher);
+    let iv = Block::<DummyCipher>::from([0xAA; 16]);
+    let mut blocks: Vec<Block<DummyCipher>> = (0..7u8).map(|i| [i; 16].into()).collect();
+
+    cbc_encrypt(&cipher, &iv, &mut blocks);
+    assert_eq!(cipher.encrypt_count(), 7);
+    assert_eq!(cipher.decrypt_count(), 0);
+
+    cipher.decrypt_blocks(&mut blocks[..5]);
+    cipher.decrypt_block(&mut blocks[5]);
+    assert_eq!(cipher.encrypt_count(), 7);
+    assert_eq!(cipher.decrypt_count(), 6);
+
+    cipher.reset_counts();
+    assert_eq!(cipher.encrypt_count(), 0);
+    assert_eq!(cipher.decrypt_count(), 0);
+}
+
+#[test]
+fn counting_from_key() {
+    let key = [0x42; 16].into();
+    let cipher = <CountingCipher<DummyKeyedCipher> as FromKey>::new(&key);
+    let inner = DummyKeyedCipher::new(&key);
+
+    let pt = Block::<DummyKeyedCipher>::from([0x11; 16]);
+    let mut block = pt;
+    let mut expected = pt;
+    cipher.encrypt_block(&mut block);
+    inner.encrypt_block(&mut expected);
+    assert_eq!(block, expected);
+    assert_eq!(cipher.encrypt_count(), 1);
+}
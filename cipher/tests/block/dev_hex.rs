@@ -0,0 +1,51 @@
+use cipher::consts::{U1, U4};
+use cipher::generic_array::GenericArray;
+use cipher::{Block, BlockCipher, BlockDecrypt, BlockEncrypt, FromKey};
+
+/// Dummy block cipher which XORs blocks with the key and rotates them.
+struct DummyCipher {
+    key: GenericArray<u8, U4>,
+}
+
+impl BlockCipher for DummyCipher {
+    type BlockSize = U4;
+    type ParBlocks = U1;
+}
+
+impl FromKey for DummyCipher {
+    type KeySize = U4;
+
+    fn new(key: &GenericArray<u8, U4>) -> Self {
+        Self { key: *key }
+    }
+}
+
+impl BlockEncrypt for DummyCipher {
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        block
+            .iter_mut()
+            .zip(self.key.iter())
+            .for_each(|(a, b)| *a ^= b);
+        block.rotate_left(1);
+    }
+}
+
+impl BlockDecrypt for DummyCipher {
+    fn decrypt_block(&self, block: &mut Block<Self>) {
+        block.rotate_right(1);
+        block
+            .iter_mut()
+            .zip(self.key.iter())
+            .for_each(|(a, b)| *a ^= b);
+    }
+}
+
+cipher::block_cipher_hex_test!(
+    dummy_hex,
+    DummyCipher,
+    &[
+        ("00000000", "00000000", "00000000"),
+        ("01020304", "090807FF", "0a04fb08"),
+        ("FFffFFff", "deadbeef", "52411021"),
+    ]
+);
@@ -0,0 +1,3 @@
+use crate::common::DummyKeyedCipher;
+
+cipher::block_par_test!(dummy_par, "dummy_par", DummyKeyedCipher);
@@ -0,0 +1,3 @@
+use crate::common::DummyKeyedCipher;
+
+cipher::block_roundtrip_test!(dummy_roundtrip, DummyKeyedCipher, [0x42u8; 16]);
@@ -0,0 +1,49 @@
+use cipher::consts::{U1, U16};
+use cipher::generic_array::GenericArray;
+use cipher::{BlockDecrypt, BlockEncrypt, DynBlockDecrypt, DynBlockEncrypt};
+
+use crate::common::{DummyCipher, DummyParCipher};
+
+#[test]
+fn dyn_block_cipher() {
+    let encs: Vec<Box<dyn DynBlockEncrypt<BlockSize = U16>>> =
+        vec![Box::new(DummyCipher), Box::new(DummyParCipher)];
+    let decs: Vec<Box<dyn DynBlockDecrypt<BlockSize = U16>>> =
+        vec![Box::new(DummyCipher), Box::new(DummyParCipher)];
+
+    let pt: GenericArray<u8, U16> =
+        GenericArray::from([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+    let mut block = pt;
+    for enc in encs.iter() {
+        enc.dyn_encrypt_block(&mut block);
+    }
+    assert_ne!(block, pt);
+
+    let mut expected = pt;
+    DummyCipher.encrypt_block(&mut expected);
+    DummyParCipher.encrypt_block(&mut expected);
+    assert_eq!(block, expected);
+
+    let mut blocks = [block; 5];
+    for dec in decs.iter().rev() {
+        dec.dyn_decrypt_blocks(&mut blocks);
+    }
+    assert_eq!(blocks, [pt; 5]);
+}
+
+#[test]
+fn block_cipher_trait_object() {
+    let cipher = DummyCipher;
+    let enc: &dyn BlockEncrypt<BlockSize = U16, ParBlocks = U1> = &cipher;
+    let dec: &dyn BlockDecrypt<BlockSize = U16, ParBlocks = U1> = &cipher;
+
+    let pt = GenericArray::from([0x11; 16]);
+    let mut expected = pt;
+    cipher.encrypt_block(&mut expected);
+
+    let mut blocks = [pt; 3];
+    enc.encrypt_blocks(&mut blocks);
+    assert_eq!(blocks, [expected; 3]);
+    dec.decrypt_blocks(&mut blocks);
+    assert_eq!(blocks, [pt; 3]);
+}
@@ -0,0 +1,50 @@
+use cipher::{Block, BlockCipherEncDec, BlockCipherEncDecMut, BlockDecrypt, BlockEncrypt};
+
+use crate::common::{DummyCipher, DummyParCipher};
+
+fn roundtrip<C: BlockCipherEncDec>(cipher: &C, blocks: &mut [Block<C>]) {
+    let pt = blocks.to_vec();
+    cipher.encrypt_blocks(blocks);
+    assert_ne!(blocks, &pt[..]);
+    cipher.decrypt_blocks(blocks);
+    assert_eq!(blocks, &pt[..]);
+}
+
+fn roundtrip_mut<C: BlockCipherEncDecMut>(cipher: &mut C, blocks: &mut [Block<C>]) {
+    let pt = blocks.to_vec();
+    blocks.iter_mut().for_each(|b| cipher.encrypt_block_mut(b));
+    assert_ne!(blocks, &pt[..]);
+    blocks.iter_mut().for_each(|b| cipher.decrypt_block_mut(b));
+    assert_eq!(blocks, &pt[..]);
+}
+
+#[test]
+fn enc_dec() {
+    let mut blocks: Vec<Block<DummyCipher>> = (0..5u8).map(|i| [i; 16].into()).collect();
+    roundtrip(&DummyCipher, &mut blocks);
+    roundtrip(&&DummyCipher, &mut blocks);
+    roundtrip(&DummyParCipher, &mut blocks);
+    roundtrip(&&DummyParCipher, &mut blocks);
+}
+
+#[test]
+fn enc_dec_mut() {
+    let mut blocks: Vec<Block<DummyCipher>> = (0..5u8).map(|i| [i; 16].into()).collect();
+    roundtrip_mut(&mut DummyCipher, &mut blocks);
+    roundtrip_mut(&mut &DummyCipher, &mut blocks);
+    roundtrip_mut(&mut DummyParCipher, &mut blocks);
+    roundtrip_mut(&mut &DummyParCipher, &mut blocks);
+}
+
+#[test]
+fn ref_matches_owned() {
+    let pt = Block::<DummyCipher>::from([0x42; 16]);
+    let mut owned = pt;
+    let mut by_ref = pt;
+    DummyCipher.encrypt_block(&mut owned);
+    <&DummyCipher as BlockEncrypt>::encrypt_block(&&DummyCipher, &mut by_ref);
+    assert_eq!(owned, by_ref);
+
+    <&DummyCipher as BlockDecrypt>::decrypt_block(&&DummyCipher, &mut by_ref);
+    assert_eq!(by_ref, pt);
+}
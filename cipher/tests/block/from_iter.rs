@@ -0,0 +1,17 @@
+use cipher::{Block, BlockDecrypt, BlockEncrypt};
+
+use crate::common::DummyCipher;
+
+#[test]
+fn from_iter() {
+    let pt: Vec<Block<DummyCipher>> = (0..5u8)
+        .map(|i| Block::<DummyCipher>::from([i; 16]))
+        .collect();
+    let mut expected = pt.clone();
+    DummyCipher.encrypt_blocks(&mut expected);
+
+    let ct: Vec<_> = DummyCipher.encrypt_from_iter(pt.iter().cloned()).collect();
+    assert_eq!(ct, expected);
+    let res: Vec<_> = DummyCipher.decrypt_from_iter(ct).collect();
+    assert_eq!(res, pt);
+}
@@ -0,0 +1,42 @@
+use cipher::errors::CapacityError;
+use cipher::{Block, BlockDecrypt, BlockDecryptMut, BlockEncrypt, BlockEncryptMut};
+
+use crate::common::DummyParCipher;
+
+fn blocks<const N: usize>() -> [Block<DummyParCipher>; N] {
+    let mut res = [Block::<DummyParCipher>::default(); N];
+    for (i, block) in res.iter_mut().enumerate() {
+        block[0] = i as u8;
+    }
+    res
+}
+
+#[test]
+fn encrypt_decrypt_to_heapless() {
+    let pt = blocks::<7>();
+    let mut expected = pt;
+    DummyParCipher.encrypt_blocks(&mut expected);
+
+    let ct = DummyParCipher.encrypt_blocks_to_heapless::<8>(&pt).unwrap();
+    assert_eq!(&ct[..], &expected[..]);
+    let res = DummyParCipher.decrypt_blocks_to_heapless::<7>(&ct).unwrap();
+    assert_eq!(&res[..], &pt[..]);
+
+    let ct = DummyParCipher
+        .encrypt_blocks_to_heapless_mut::<7>(&pt)
+        .unwrap();
+    assert_eq!(&ct[..], &expected[..]);
+    let res = DummyParCipher
+        .decrypt_blocks_to_heapless_mut::<8>(&ct)
+        .unwrap();
+    assert_eq!(&res[..], &pt[..]);
+}
+
+#[test]
+fn heapless_capacity_overflow() {
+    let pt = blocks::<5>();
+    let res = DummyParCipher.encrypt_blocks_to_heapless::<4>(&pt);
+    assert_eq!(res.err(), Some(CapacityError));
+    let res = DummyParCipher.decrypt_blocks_to_heapless_mut::<4>(&pt);
+    assert_eq!(res.err(), Some(CapacityError));
+}
@@ -0,0 +1,288 @@
+use cipher::consts::{U1, U16, U24, U32};
+use cipher::errors::KeyWrapError;
+use cipher::generic_array::{ArrayLength, GenericArray};
+use cipher::{Block, BlockCipher, BlockDecrypt, BlockEncrypt, FromKey, KeyWrap};
+use core::marker::PhantomData;
+
+fn xtime(x: u8) -> u8 {
+    (x << 1) ^ if x & 0x80 != 0 { 0x1b } else { 0 }
+}
+
+fn gmul(mut a: u8, mut b: u8) -> u8 {
+    let mut p = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            p ^= a;
+        }
+        a = xtime(a);
+        b >>= 1;
+    }
+    p
+}
+
+/// Straightforward (slow and not constant-time) AES implementation used
+/// to check key wrapping against the RFC 3394 test vectors.
+struct Aes<K> {
+    round_keys: Vec<[u8; 16]>,
+    sbox: [u8; 256],
+    inv_sbox: [u8; 256],
+    _pd: PhantomData<K>,
+}
+
+impl<K: ArrayLength<u8>> BlockCipher for Aes<K> {
+    type BlockSize = U16;
+    type ParBlocks = U1;
+}
+
+impl<K: ArrayLength<u8>> FromKey for Aes<K> {
+    type KeySize = K;
+
+    fn new(key: &GenericArray<u8, K>) -> Self {
+        let mut sbox = [0u8; 256];
+        let mut inv_sbox = [0u8; 256];
+        for x in 0..=255u8 {
+            let inv = (0..=255u8).find(|&y| gmul(x, y) == 1).unwrap_or(0);
+            let s = inv
+                ^ inv.rotate_left(1)
+                ^ inv.rotate_left(2)
+                ^ inv.rotate_left(3)
+                ^ inv.rotate_left(4)
+                ^ 0x63;
+            sbox[x as usize] = s;
+            inv_sbox[s as usize] = x;
+        }
+
+        let nk = key.len() / 4;
+        let nr = nk + 6;
+        let mut w: Vec<[u8; 4]> = key.chunks(4).map(|c| [c[0], c[1], c[2], c[3]]).collect();
+        let mut rcon = 1u8;
+        for i in nk..4 * (nr + 1) {
+            let mut t = w[i - 1];
+            if i % nk == 0 {
+                t.rotate_left(1);
+                t.iter_mut().for_each(|b| *b = sbox[*b as usize]);
+                t[0] ^= rcon;
+                rcon = xtime(rcon);
+            } else if nk > 6 && i % nk == 4 {
+                t.iter_mut().for_each(|b| *b = sbox[*b as usize]);
+            }
+            let prev = w[i - nk];
+            w.push([
+                prev[0] ^ t[0],
+                prev[1] ^ t[1],
+                prev[2] ^ t[2],
+                prev[3] ^ t[3],
+            ]);
+        }
+        let round_keys = w
+            .chunks(4)
+            .map(|c| {
+                let mut rk = [0u8; 16];
+                rk.chunks_mut(4)
+                    .zip(c)
+                    .for_each(|(a, b)| a.copy_from_slice(b));
+                rk
+            })
+            .collect();
+
+        Self {
+            round_keys,
+            sbox,
+            inv_sbox,
+            _pd: PhantomData,
+        }
+    }
+}
+
+fn add_round_key(s: &mut [u8], rk: &[u8; 16]) {
+    s.iter_mut().zip(rk).for_each(|(a, b)| *a ^= b);
+}
+
+fn mix_column(c: &mut [u8], m: [u8; 4]) {
+    let a = [c[0], c[1], c[2], c[3]];
+    for (r, b) in c.iter_mut().enumerate() {
+        *b = (0..4).fold(0, |acc, k| acc ^ gmul(a[k], m[(4 + k - r) % 4]));
+    }
+}
+
+impl<K: ArrayLength<u8>> BlockEncrypt for Aes<K> {
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        let nr = self.round_keys.len() - 1;
+        add_round_key(block, &self.round_keys[0]);
+        for round in 1..=nr {
+            block.iter_mut().for_each(|b| *b = self.sbox[*b as usize]);
+            let s = *block;
+            for c in 0..4 {
+                for r in 0..4 {
+                    block[4 * c + r] = s[4 * ((c + r) % 4) + r];
+                }
+            }
+            if round != nr {
+                block
+                    .chunks_mut(4)
+                    .for_each(|c| mix_column(c, [2, 3, 1, 1]));
+            }
+            add_round_key(block, &self.round_keys[round]);
+        }
+    }
+}
+
+impl<K: ArrayLength<u8>> BlockDecrypt for Aes<K> {
+    fn decrypt_block(&self, block: &mut Block<Self>) {
+        let nr = self.round_keys.len() - 1;
+        for round in (1..=nr).rev() {
+            add_round_key(block, &self.round_keys[round]);
+            if round != nr {
+                block
+                    .chunks_mut(4)
+                    .for_each(|c| mix_column(c, [14, 11, 13, 9]));
+            }
+            let s = *block;
+            for c in 0..4 {
+                for r in 0..4 {
+                    block[4 * ((c + r) % 4) + r] = s[4 * c + r];
+                }
+            }
+            block
+                .iter_mut()
+                .for_each(|b| *b = self.inv_sbox[*b as usize]);
+        }
+        add_round_key(block, &self.round_keys[0]);
+    }
+}
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn aes_fips197() {
+    let pt = hex("00112233445566778899aabbccddeeff");
+    let vectors: &[(&str, &str)] = &[
+        (
+            "000102030405060708090a0b0c0d0e0f",
+            "69c4e0d86a7b0430d8cdb78070b4c55a",
+        ),
+        (
+            "000102030405060708090a0b0c0d0e0f1011121314151617",
+            "dda97ca4864cdfe06eaf70a0ec0d7191",
+        ),
+        (
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+            "8ea2b7ca516745bfeafc49904b496089",
+        ),
+    ];
+    for &(key, ct) in vectors {
+        let key = hex(key);
+        let mut block = GenericArray::clone_from_slice(&pt);
+        match key.len() {
+            16 => Aes::<U16>::new_from_slice(&key)
+                .unwrap()
+                .encrypt_block(&mut block),
+            24 => Aes::<U24>::new_from_slice(&key)
+                .unwrap()
+                .encrypt_block(&mut block),
+            _ => Aes::<U32>::new_from_slice(&key)
+                .unwrap()
+                .encrypt_block(&mut block),
+        }
+        assert_eq!(block[..], hex(ct)[..]);
+    }
+}
+
+fn check<C: FromKey + KeyWrap>(kek: &str, data: &str, wrapped: &str) {
+    let cipher = C::new_from_slice(&hex(kek)).unwrap();
+    let data = hex(data);
+    let wrapped = hex(wrapped);
+
+    let mut out = vec![0u8; data.len() + 8];
+    cipher.wrap(&data, &mut out).unwrap();
+    assert_eq!(out, wrapped);
+
+    let mut out = vec![0u8; data.len()];
+    cipher.unwrap(&wrapped, &mut out).unwrap();
+    assert_eq!(out, data);
+
+    let mut corrupted = wrapped.clone();
+    corrupted[10] ^= 1;
+    assert_eq!(
+        cipher.unwrap(&corrupted, &mut out),
+        Err(KeyWrapError::IntegrityCheckFailed)
+    );
+    assert!(out.iter().all(|&b| b == 0));
+}
+
+/// Test vectors from RFC 3394 section 4.
+#[test]
+fn rfc3394() {
+    const KEK128: &str = "000102030405060708090A0B0C0D0E0F";
+    const KEK192: &str = "000102030405060708090A0B0C0D0E0F1011121314151617";
+    const KEK256: &str = "000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F";
+    const DATA128: &str = "00112233445566778899AABBCCDDEEFF";
+    const DATA192: &str = "00112233445566778899AABBCCDDEEFF0001020304050607";
+    const DATA256: &str = "00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F";
+
+    check::<Aes<U16>>(
+        KEK128,
+        DATA128,
+        "1FA68B0A8112B447AEF34BD8FB5A7B829D3E862371D2CFE5",
+    );
+    check::<Aes<U24>>(
+        KEK192,
+        DATA128,
+        "96778B25AE6CA435F92B5B97C050AED2468AB8A17AD84E5D",
+    );
+    check::<Aes<U32>>(
+        KEK256,
+        DATA128,
+        "64E8C3F9CE0F5BA263E9777905818A2A93C8191E7D6E8AE7",
+    );
+    check::<Aes<U24>>(
+        KEK192,
+        DATA192,
+        "031D33264E15D33268F24EC260743EDCE1C6C7DDEE725A936BA814915C6762D2",
+    );
+    check::<Aes<U32>>(
+        KEK256,
+        DATA192,
+        "A8F9BC1612C68B3FF6E6F4FBE30E71E4769C8B80A32CB8958CD5D17D6B254DA1",
+    );
+    check::<Aes<U32>>(
+        KEK256,
+        DATA256,
+        "28C9F404C4B810F4CBCCB35CFB87F8263F5786E2D80ED326CBC7F0E71A99F43BFB988B9B7A02DD21",
+    );
+}
+
+#[test]
+fn invalid_lengths() {
+    let cipher = Aes::<U16>::new(&[0; 16].into());
+    let mut out = [0u8; 32];
+    assert_eq!(
+        cipher.wrap(&[0; 8], &mut out[..16]),
+        Err(KeyWrapError::InvalidDataLength)
+    );
+    assert_eq!(
+        cipher.wrap(&[0; 20], &mut out[..28]),
+        Err(KeyWrapError::InvalidDataLength)
+    );
+    assert_eq!(
+        cipher.wrap(&[0; 16], &mut out),
+        Err(KeyWrapError::InvalidOutputLength)
+    );
+    assert_eq!(
+        cipher.unwrap(&[0; 16], &mut out[..8]),
+        Err(KeyWrapError::InvalidDataLength)
+    );
+    assert_eq!(
+        cipher.unwrap(&[0; 25], &mut out[..17]),
+        Err(KeyWrapError::InvalidDataLength)
+    );
+    assert_eq!(
+        cipher.unwrap(&[0; 24], &mut out),
+        Err(KeyWrapError::InvalidOutputLength)
+    );
+}
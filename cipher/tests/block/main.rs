@@ -0,0 +1,42 @@
+//! Tests for block cipher traits and helpers.
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod array;
+mod as_blocks;
+mod b2b_uninit;
+mod block_ret;
+mod blocks_and;
+mod cbc_parallel;
+#[cfg(feature = "dev")]
+mod counting;
+#[cfg(feature = "dev")]
+mod dev_hex;
+#[cfg(feature = "dev")]
+mod dev_par;
+#[cfg(feature = "dev")]
+mod dev_roundtrip;
+mod dyn_block;
+mod enc_dec;
+mod from_iter;
+#[cfg(feature = "heapless")]
+mod heapless;
+mod key_wrap;
+#[cfg(feature = "block-padding")]
+mod padding;
+mod par_blocks;
+mod preferred_align;
+mod ratchet;
+#[cfg(feature = "rayon")]
+mod rayon;
+mod retain;
+mod self_test;
+#[cfg(feature = "subtle")]
+mod subtle;
+#[cfg(feature = "alloc")]
+mod to_vec;
+mod try_blocks;
+mod tweakable;
+#[cfg(feature = "dev")]
+mod variable_block;
@@ -0,0 +1,105 @@
+use cipher::block_padding::{Iso7816, NoPadding, Padding, Pkcs7, ZeroPadding};
+use cipher::consts::U16;
+use cipher::errors::PadError;
+use cipher::generic_array::GenericArray;
+use cipher::{BlockDecryptMut, BlockEncrypt, BlockEncryptMut};
+
+use crate::common::DummyCipher;
+
+const BS: usize = 16;
+
+fn roundtrip<P: Padding<U16>>(msg_len: usize, padded_len: usize) {
+    let msg: Vec<u8> = (0..msg_len).map(|i| i as u8 + 1).collect();
+    let mut buf = [0u8; 4 * BS];
+    buf[..msg_len].copy_from_slice(&msg);
+
+    let ct = DummyCipher
+        .encrypt_padded_mut::<P>(&mut buf, msg_len)
+        .unwrap();
+    assert_eq!(ct.len(), padded_len);
+    let ct_len = ct.len();
+
+    let pt = DummyCipher
+        .decrypt_padded_mut::<P>(&mut buf[..ct_len])
+        .unwrap();
+    assert_eq!(pt, &msg[..]);
+}
+
+#[test]
+fn pkcs7_roundtrip() {
+    roundtrip::<Pkcs7>(0, BS);
+    roundtrip::<Pkcs7>(BS - 1, BS);
+    roundtrip::<Pkcs7>(BS, 2 * BS);
+    roundtrip::<Pkcs7>(BS + 1, 2 * BS);
+}
+
+#[test]
+fn iso7816_roundtrip() {
+    roundtrip::<Iso7816>(0, BS);
+    roundtrip::<Iso7816>(BS - 1, BS);
+    roundtrip::<Iso7816>(BS, 2 * BS);
+    roundtrip::<Iso7816>(BS + 1, 2 * BS);
+}
+
+#[test]
+fn zero_padding_roundtrip() {
+    roundtrip::<ZeroPadding>(0, BS);
+    roundtrip::<ZeroPadding>(BS - 1, BS);
+    // block-aligned message gets a full block of zeros appended
+    roundtrip::<ZeroPadding>(BS, 2 * BS);
+    roundtrip::<ZeroPadding>(BS + 1, 2 * BS);
+
+    let mut buf = [0xFFu8; 2 * BS];
+    let ct_len = DummyCipher
+        .encrypt_padded_mut::<ZeroPadding>(&mut buf, BS)
+        .unwrap()
+        .len();
+    assert_eq!(ct_len, 2 * BS);
+    DummyCipher.decrypt_block_mut(GenericArray::from_mut_slice(&mut buf[BS..]));
+    assert_eq!(buf[BS..], [0u8; BS]);
+}
+
+#[test]
+fn no_padding() {
+    roundtrip::<NoPadding>(0, 0);
+    roundtrip::<NoPadding>(BS, BS);
+    let mut buf = [0u8; 2 * BS];
+    let res = DummyCipher.encrypt_padded_mut::<NoPadding>(&mut buf, BS + 1);
+    assert_eq!(res, Err(PadError));
+}
+
+#[test]
+fn small_buffer() {
+    let mut buf = [0u8; BS];
+    let res = DummyCipher.encrypt_padded_mut::<Pkcs7>(&mut buf, BS);
+    assert_eq!(res, Err(PadError));
+    let res = DummyCipher.decrypt_padded_mut::<Pkcs7>(&mut buf[..BS - 1]);
+    assert!(res.is_err());
+    let res = DummyCipher.decrypt_padded_mut::<Pkcs7>(&mut []);
+    assert!(res.is_err());
+}
+
+#[test]
+fn buf_padded() {
+    for msg_len in 0..=3 * BS {
+        let msg: Vec<u8> = (0..msg_len).map(|i| i as u8 + 1).collect();
+        let mut expected = [0u8; 4 * BS];
+        expected[..msg_len].copy_from_slice(&msg);
+        let mut buf = expected;
+
+        let expected = DummyCipher
+            .encrypt_padded_mut::<Pkcs7>(&mut expected, msg_len)
+            .unwrap();
+        let ct_len = DummyCipher
+            .encrypt_buf_padded::<Pkcs7>(&mut buf, msg_len)
+            .unwrap();
+        assert_eq!(ct_len, (msg_len / BS + 1) * BS);
+        assert_eq!(&buf[..ct_len], expected);
+    }
+
+    let mut buf = [0u8; 2 * BS];
+    let res = DummyCipher.encrypt_buf_padded::<Pkcs7>(&mut buf, 2 * BS);
+    assert_eq!(res, Err(PadError));
+    let res = DummyCipher.encrypt_buf_padded::<Pkcs7>(&mut buf[..BS + 3], BS + 3);
+    assert_eq!(res, Err(PadError));
+}
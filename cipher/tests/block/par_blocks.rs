@@ -0,0 +1,82 @@
+use cipher::consts::{U1, U16, U3, U6, U8};
+use cipher::generic_array::{ArrayLength, GenericArray};
+use cipher::{Block, BlockCipher, BlockEncrypt, ParBlocks};
+use core::cell::Cell;
+use core::marker::PhantomData;
+
+/// Dummy block cipher which counts calls of the single and parallel
+/// block methods.
+struct CountingCipher<N> {
+    single: Cell<usize>,
+    par: Cell<usize>,
+    _pd: PhantomData<N>,
+}
+
+impl<N> CountingCipher<N> {
+    fn new() -> Self {
+        Self {
+            single: Cell::new(0),
+            par: Cell::new(0),
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<N: ArrayLength<GenericArray<u8, U16>>> BlockCipher for CountingCipher<N> {
+    type BlockSize = U16;
+    type ParBlocks = N;
+}
+
+impl<N: ArrayLength<GenericArray<u8, U16>>> BlockEncrypt for CountingCipher<N> {
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        self.single.set(self.single.get() + 1);
+        block.iter_mut().for_each(|b| *b = b.wrapping_add(1));
+    }
+
+    fn encrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        self.par.set(self.par.get() + 1);
+        for block in blocks.iter_mut() {
+            block.iter_mut().for_each(|b| *b = b.wrapping_add(1));
+        }
+    }
+}
+
+fn check<N: ArrayLength<GenericArray<u8, U16>>>() {
+    let width = N::to_usize();
+    assert_eq!(CountingCipher::<N>::par_blocks(), width);
+    assert_eq!(<&CountingCipher<N>>::par_blocks(), width);
+    for n in 0..4 * width + 2 {
+        let cipher = CountingCipher::<N>::new();
+        let mut blocks = vec![Block::<CountingCipher<N>>::default(); n];
+        cipher.encrypt_blocks(&mut blocks);
+        assert!(blocks.iter().flatten().all(|&b| b == 1));
+
+        if width > 1 {
+            assert_eq!(cipher.par.get(), n / width, "width {}, len {}", width, n);
+            assert_eq!(cipher.single.get(), n % width, "width {}, len {}", width, n);
+        } else {
+            assert_eq!(cipher.par.get(), 0);
+            assert_eq!(cipher.single.get(), n);
+        }
+    }
+}
+
+#[test]
+fn par_blocks_width_1() {
+    check::<U1>();
+}
+
+#[test]
+fn par_blocks_width_3() {
+    check::<U3>();
+}
+
+#[test]
+fn par_blocks_width_6() {
+    check::<U6>();
+}
+
+#[test]
+fn par_blocks_width_8() {
+    check::<U8>();
+}
@@ -0,0 +1,20 @@
+use cipher::BlockCipherAlign;
+
+use crate::common::{DummyCipher, DummyParCipher};
+
+impl BlockCipherAlign for DummyCipher {}
+
+impl BlockCipherAlign for DummyParCipher {
+    const PREFERRED_ALIGN: usize = 32;
+}
+
+fn preferred_align<C: BlockCipherAlign>() -> usize {
+    C::PREFERRED_ALIGN
+}
+
+#[test]
+fn preferred_align_hint() {
+    assert_eq!(preferred_align::<DummyCipher>(), 1);
+    assert_eq!(preferred_align::<DummyParCipher>(), 32);
+    assert_eq!(preferred_align::<&DummyParCipher>(), 32);
+}
@@ -0,0 +1,34 @@
+use cipher::{block_cipher_ratchet, Block, BlockEncrypt, FromKey, Ratchet};
+
+use crate::common::DummyKeyedCipher;
+
+impl Ratchet for DummyKeyedCipher {
+    fn ratchet(&mut self) {
+        block_cipher_ratchet(self);
+    }
+}
+
+fn encrypt(cipher: &DummyKeyedCipher) -> Block<DummyKeyedCipher> {
+    let mut block = Block::<DummyKeyedCipher>::from([0x42; 16]);
+    cipher.encrypt_block(&mut block);
+    block
+}
+
+#[test]
+fn ratchet() {
+    let key = (0..16u8).collect::<Vec<_>>();
+    let mut c1 = DummyKeyedCipher::new_from_slice(&key).unwrap();
+    let mut c2 = c1.clone();
+    let before = encrypt(&c1);
+
+    c1.ratchet();
+    c2.ratchet();
+    assert_ne!(c1.key[..], key[..]);
+    assert_eq!(c1.key, c2.key);
+    assert_ne!(encrypt(&c1), before);
+    assert_eq!(encrypt(&c1), encrypt(&c2));
+
+    let first = c1.key;
+    c1.ratchet();
+    assert_ne!(c1.key, first);
+}
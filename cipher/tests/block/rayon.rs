@@ -0,0 +1,26 @@
+use cipher::{Block, BlockDecrypt, BlockEncrypt};
+
+use crate::common::DummyParCipher;
+
+#[test]
+fn par_encrypt_decrypt() {
+    // 4 MiB of data plus a tail which is not multiple of `ParBlocks`
+    let n = (1 << 18) + 3;
+    let pt: Vec<Block<DummyParCipher>> = (0..n)
+        .map(|i: usize| {
+            let mut block = Block::<DummyParCipher>::default();
+            block[..8].copy_from_slice(&(i as u64).to_le_bytes());
+            block
+        })
+        .collect();
+
+    let mut ct1 = pt.clone();
+    DummyParCipher.encrypt_blocks(&mut ct1);
+    let mut ct2 = pt.clone();
+    DummyParCipher.par_encrypt_blocks(&mut ct2);
+    assert_eq!(ct1, ct2);
+    assert_ne!(ct1, pt);
+
+    DummyParCipher.par_decrypt_blocks(&mut ct2);
+    assert_eq!(ct2, pt);
+}
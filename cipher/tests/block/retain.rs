@@ -0,0 +1,15 @@
+use cipher::{Block, BlockEncrypt, BlockEncryptMut};
+
+use crate::common::DummyCipher;
+
+#[test]
+fn encrypt_block_retain() {
+    let pt = Block::<DummyCipher>::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    let mut expected = pt;
+    DummyCipher.encrypt_block(&mut expected);
+
+    let mut block = pt;
+    let prev = DummyCipher.encrypt_block_retain(&mut block);
+    assert_eq!(prev, pt);
+    assert_eq!(block, expected);
+}
@@ -0,0 +1,61 @@
+use cipher::consts::{U1, U16};
+use cipher::errors::SelfTestError;
+use cipher::generic_array::GenericArray;
+use cipher::{Block, BlockCipher, BlockEncrypt, FromKey, SelfTest};
+
+use crate::common::DummyKeyedCipher;
+
+/// Same as `DummyKeyedCipher`, but ignores the key.
+struct BrokenCipher;
+
+impl BlockCipher for BrokenCipher {
+    type BlockSize = U16;
+    type ParBlocks = U1;
+}
+
+impl FromKey for BrokenCipher {
+    type KeySize = U16;
+
+    fn new(_: &GenericArray<u8, U16>) -> Self {
+        Self
+    }
+}
+
+impl BlockEncrypt for BrokenCipher {
+    fn encrypt_block(&self, _: &mut Block<Self>) {}
+}
+
+fn kat<C: FromKey<KeySize = U16> + BlockEncrypt<BlockSize = U16>>() -> Result<(), SelfTestError> {
+    let cipher = C::new(&[0x0F; 16].into());
+    let mut block = Block::<C>::from([0xF0; 16]);
+    cipher.encrypt_block(&mut block);
+    if block == [0xFF; 16].into() {
+        Ok(())
+    } else {
+        Err(SelfTestError)
+    }
+}
+
+impl SelfTest for DummyKeyedCipher {
+    fn self_test() -> Result<(), SelfTestError> {
+        kat::<Self>()
+    }
+}
+
+impl SelfTest for BrokenCipher {
+    fn self_test() -> Result<(), SelfTestError> {
+        kat::<Self>()
+    }
+}
+
+#[test]
+fn self_test() {
+    let key = [1; 16].into();
+    assert!(DummyKeyedCipher::self_test().is_ok());
+    assert!(DummyKeyedCipher::new_checked(&key).is_ok());
+    assert_eq!(BrokenCipher::self_test(), Err(SelfTestError));
+    assert!(matches!(
+        BrokenCipher::new_checked(&key),
+        Err(SelfTestError)
+    ));
+}
@@ -0,0 +1,30 @@
+use cipher::{ct_eq_blocks, ct_eq_blocks_slice, Block};
+
+use crate::common::DummyCipher;
+
+fn block(b: u8) -> Block<DummyCipher> {
+    Block::<DummyCipher>::from([b; 16])
+}
+
+#[test]
+fn ct_eq_single() {
+    let a = block(1);
+    let mut b = block(1);
+    assert_eq!(ct_eq_blocks::<DummyCipher>(&a, &b).unwrap_u8(), 1);
+    b[15] ^= 1;
+    assert_eq!(ct_eq_blocks::<DummyCipher>(&a, &b).unwrap_u8(), 0);
+}
+
+#[test]
+fn ct_eq_slice() {
+    let a = [block(1), block(2), block(3)];
+    let mut b = a;
+    assert_eq!(ct_eq_blocks_slice::<DummyCipher>(&a, &b).unwrap_u8(), 1);
+    assert_eq!(ct_eq_blocks_slice::<DummyCipher>(&[], &[]).unwrap_u8(), 1);
+    assert_eq!(
+        ct_eq_blocks_slice::<DummyCipher>(&a, &b[..2]).unwrap_u8(),
+        0
+    );
+    b[1][0] ^= 0x80;
+    assert_eq!(ct_eq_blocks_slice::<DummyCipher>(&a, &b).unwrap_u8(), 0);
+}
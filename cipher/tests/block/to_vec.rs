@@ -0,0 +1,30 @@
+use cipher::{Block, BlockDecrypt, BlockDecryptMut, BlockEncrypt, BlockEncryptMut};
+
+use crate::common::DummyParCipher;
+
+fn blocks(n: usize) -> Vec<Block<DummyParCipher>> {
+    (0..n)
+        .map(|i| {
+            let mut block = Block::<DummyParCipher>::default();
+            block[0] = i as u8;
+            block
+        })
+        .collect()
+}
+
+#[test]
+fn encrypt_decrypt_to_vec() {
+    for n in [0, 1, 4, 7] {
+        let pt = blocks(n);
+        let mut expected = pt.clone();
+        DummyParCipher.encrypt_blocks(&mut expected);
+
+        let ct = DummyParCipher.encrypt_blocks_to_vec(&pt);
+        assert_eq!(ct, expected);
+        assert_eq!(DummyParCipher.decrypt_blocks_to_vec(&ct), pt);
+
+        let ct = DummyParCipher.encrypt_blocks_to_vec_mut(&pt);
+        assert_eq!(ct, expected);
+        assert_eq!(DummyParCipher.decrypt_blocks_to_vec_mut(&ct), pt);
+    }
+}
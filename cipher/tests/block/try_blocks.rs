@@ -0,0 +1,74 @@
+use cipher::consts::{U1, U16};
+use cipher::errors::BlockError;
+use cipher::{Block, BlockCipher, BlockDecryptMut, BlockEncrypt, BlockEncryptMut};
+
+use crate::common::DummyCipher;
+
+/// Mock hardware backend which processes at most 3 blocks per call and
+/// fails after `budget` blocks.
+struct MockPeripheral {
+    budget: usize,
+}
+
+impl BlockCipher for MockPeripheral {
+    type BlockSize = U16;
+    type ParBlocks = U1;
+}
+
+impl BlockEncryptMut for MockPeripheral {
+    fn encrypt_block_mut(&mut self, block: &mut Block<Self>) {
+        DummyCipher.encrypt_block(block);
+    }
+
+    fn try_encrypt_blocks_mut(&mut self, blocks: &mut [Block<Self>]) -> Result<usize, BlockError> {
+        if self.budget == 0 {
+            return Err(BlockError);
+        }
+        let n = blocks.len().min(3).min(self.budget);
+        for block in &mut blocks[..n] {
+            self.encrypt_block_mut(block);
+        }
+        self.budget -= n;
+        Ok(n)
+    }
+}
+
+fn blocks() -> Vec<Block<DummyCipher>> {
+    (0..8u8).map(|i| [i; 16].into()).collect()
+}
+
+#[test]
+fn try_blocks_default() {
+    let mut expected = blocks();
+    DummyCipher.encrypt_blocks(&mut expected);
+
+    let mut buf = blocks();
+    assert_eq!(DummyCipher.try_encrypt_blocks_mut(&mut buf), Ok(8));
+    assert_eq!(buf, expected);
+    assert_eq!(DummyCipher.try_decrypt_blocks_mut(&mut buf), Ok(8));
+    assert_eq!(buf, blocks());
+}
+
+#[test]
+fn try_blocks_partial() {
+    let mut expected = blocks();
+    DummyCipher.encrypt_blocks(&mut expected);
+
+    let mut mock = MockPeripheral { budget: 100 };
+    let mut buf = blocks();
+    let mut pos = 0;
+    while pos < buf.len() {
+        let n = mock.try_encrypt_blocks_mut(&mut buf[pos..]).unwrap();
+        assert!(n <= 3);
+        pos += n;
+    }
+    assert_eq!(buf, expected);
+
+    let mut mock = MockPeripheral { budget: 4 };
+    let mut buf = blocks();
+    assert_eq!(mock.try_encrypt_blocks_mut(&mut buf), Ok(3));
+    assert_eq!(mock.try_encrypt_blocks_mut(&mut buf[3..]), Ok(1));
+    assert_eq!(mock.try_encrypt_blocks_mut(&mut buf[4..]), Err(BlockError));
+    assert_eq!(buf[..4], expected[..4]);
+    assert_eq!(buf[4..], blocks()[4..]);
+}
@@ -0,0 +1,116 @@
+use cipher::consts::{U1, U16, U8};
+use cipher::errors::NotEqualError;
+use cipher::{
+    Block, BlockCipher, Tweak, TweakableBlockCipher, TweakableBlockDecrypt, TweakableBlockEncrypt,
+};
+
+use crate::common::{dummy_decrypt, dummy_encrypt};
+
+/// Dummy tweakable block cipher which XORs the tweak into the first half of
+/// the block before and after applying the dummy block cipher.
+struct DummyTweakable;
+
+impl BlockCipher for DummyTweakable {
+    type BlockSize = U16;
+    type ParBlocks = U1;
+}
+
+impl TweakableBlockCipher for DummyTweakable {
+    type TweakSize = U8;
+}
+
+fn xor_tweak(tweak: &Tweak<DummyTweakable>, block: &mut Block<DummyTweakable>) {
+    block
+        .iter_mut()
+        .zip(tweak.iter())
+        .for_each(|(a, b)| *a ^= b);
+}
+
+impl TweakableBlockEncrypt for DummyTweakable {
+    fn encrypt_block_with_tweak(&self, tweak: &Tweak<Self>, block: &mut Block<Self>) {
+        xor_tweak(tweak, block);
+        dummy_encrypt(block);
+        xor_tweak(tweak, block);
+    }
+}
+
+impl TweakableBlockDecrypt for DummyTweakable {
+    fn decrypt_block_with_tweak(&self, tweak: &Tweak<Self>, block: &mut Block<Self>) {
+        xor_tweak(tweak, block);
+        dummy_decrypt(block);
+        xor_tweak(tweak, block);
+    }
+}
+
+fn tweaks(n: u8) -> Vec<Tweak<DummyTweakable>> {
+    (0..n).map(|i| [i; 8].into()).collect()
+}
+
+#[test]
+fn tweakable_roundtrip() {
+    let pt = Block::<DummyTweakable>::from([0x42; 16]);
+    let tweak = Tweak::<DummyTweakable>::from([7; 8]);
+
+    let mut block = pt;
+    DummyTweakable.encrypt_block_with_tweak(&tweak, &mut block);
+    assert_ne!(block, pt);
+    DummyTweakable.decrypt_block_with_tweak(&tweak, &mut block);
+    assert_eq!(block, pt);
+}
+
+#[test]
+fn tweakable_different_tweaks() {
+    let pt = Block::<DummyTweakable>::from([0x42; 16]);
+    let cts: Vec<_> = tweaks(4)
+        .iter()
+        .map(|tweak| {
+            let mut block = pt;
+            DummyTweakable.encrypt_block_with_tweak(tweak, &mut block);
+            block
+        })
+        .collect();
+    for i in 0..cts.len() {
+        for j in i + 1..cts.len() {
+            assert_ne!(cts[i], cts[j]);
+        }
+    }
+}
+
+#[test]
+fn tweakable_slice() {
+    let tweaks = tweaks(5);
+    let pt = vec![Block::<DummyTweakable>::from([0x42; 16]); 5];
+
+    let mut blocks = pt.clone();
+    DummyTweakable
+        .encrypt_blocks_with_tweaks(&tweaks, &mut blocks)
+        .unwrap();
+    for ((tweak, pt), ct) in tweaks.iter().zip(&pt).zip(&blocks) {
+        let mut block = *pt;
+        DummyTweakable.encrypt_block_with_tweak(tweak, &mut block);
+        assert_eq!(&block, ct);
+    }
+    // equal plaintext blocks are encrypted with different tweaks
+    assert_ne!(blocks[0], blocks[1]);
+
+    DummyTweakable
+        .decrypt_blocks_with_tweaks(&tweaks, &mut blocks)
+        .unwrap();
+    assert_eq!(blocks, pt);
+}
+
+#[test]
+fn tweakable_slice_len_mismatch() {
+    let tweaks = tweaks(3);
+    let pt = vec![Block::<DummyTweakable>::from([0x42; 16]); 4];
+    let mut blocks = pt.clone();
+    assert_eq!(
+        DummyTweakable.encrypt_blocks_with_tweaks(&tweaks, &mut blocks),
+        Err(NotEqualError)
+    );
+    assert_eq!(
+        DummyTweakable.decrypt_blocks_with_tweaks(&tweaks, &mut blocks),
+        Err(NotEqualError)
+    );
+    assert_eq!(blocks, pt);
+}
@@ -0,0 +1,39 @@
+use cipher::VariableBlockCipher;
+
+/// Dummy cipher which XORs blocks with a constant and rotates them by a
+/// block size dependent amount.
+struct DummyCipher {
+    block_size: usize,
+}
+
+impl DummyCipher {
+    fn new(block_size: usize) -> Self {
+        assert!(Self::is_block_size_supported(block_size));
+        Self { block_size }
+    }
+}
+
+impl VariableBlockCipher for DummyCipher {
+    const BLOCK_SIZES: &'static [usize] = &[16, 24, 32];
+
+    fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    fn encrypt_block_inner(&self, block: &mut [u8]) {
+        block.iter_mut().for_each(|b| *b ^= 0xA5);
+        block.rotate_left(self.block_size / 8);
+    }
+
+    fn decrypt_block_inner(&self, block: &mut [u8]) {
+        block.rotate_right(self.block_size / 8);
+        block.iter_mut().for_each(|b| *b ^= 0xA5);
+    }
+}
+
+cipher::variable_block_cipher_test!(dummy_variable_block, DummyCipher, DummyCipher::new);
+
+#[test]
+fn unsupported_block_size() {
+    assert!(!DummyCipher::is_block_size_supported(8));
+}
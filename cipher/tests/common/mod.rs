@@ -0,0 +1,217 @@
+//! Dummy ciphers shared by the integration tests.
+#![allow(dead_code)]
+
+use cipher::consts::{U1, U16, U4};
+use cipher::errors::{LoopError, OverflowError};
+use cipher::generic_array::GenericArray;
+use cipher::{
+    Block, BlockCipher, BlockDecrypt, BlockEncrypt, BlockMode, BlockModeEncrypt, BlockModeIvState,
+    BlockModeReinit, FromKey, FromKeyNonce, ParBlocks, SeekNum, StreamCipher,
+    StreamCipherCapabilities, StreamCipherSeek,
+};
+
+/// Length of the [`DummyStreamCipher`] keystream in bytes.
+pub const KEYSTREAM_LEN: u64 = 1000;
+
+/// XOR block with a constant and rotate it.
+pub fn dummy_encrypt(block: &mut [u8]) {
+    block.iter_mut().for_each(|b| *b ^= 0xA5);
+    block.rotate_left(3);
+}
+
+/// Inverse of [`dummy_encrypt`].
+pub fn dummy_decrypt(block: &mut [u8]) {
+    block.rotate_right(3);
+    block.iter_mut().for_each(|b| *b ^= 0xA5);
+}
+
+/// Dummy block cipher which XORs blocks with a constant and rotates them.
+pub struct DummyCipher;
+
+impl BlockCipher for DummyCipher {
+    type BlockSize = U16;
+    type ParBlocks = U1;
+}
+
+impl BlockEncrypt for DummyCipher {
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        dummy_encrypt(block);
+    }
+}
+
+impl BlockDecrypt for DummyCipher {
+    fn decrypt_block(&self, block: &mut Block<Self>) {
+        dummy_decrypt(block);
+    }
+}
+
+/// Same as [`DummyCipher`], but processes 4 blocks in parallel.
+pub struct DummyParCipher;
+
+impl BlockCipher for DummyParCipher {
+    type BlockSize = U16;
+    type ParBlocks = U4;
+}
+
+impl BlockEncrypt for DummyParCipher {
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        dummy_encrypt(block);
+    }
+}
+
+impl BlockDecrypt for DummyParCipher {
+    fn decrypt_block(&self, block: &mut Block<Self>) {
+        dummy_decrypt(block);
+    }
+}
+
+/// Dummy keyed block cipher which XORs blocks with the key and rotates them.
+///
+/// Processes 4 blocks in parallel with a dedicated implementation of the
+/// parallel methods.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DummyKeyedCipher {
+    pub key: GenericArray<u8, U16>,
+}
+
+impl DummyKeyedCipher {
+    fn xor_key(&self, block: &mut [u8]) {
+        block
+            .iter_mut()
+            .zip(self.key.iter())
+            .for_each(|(a, b)| *a ^= b);
+    }
+}
+
+impl BlockCipher for DummyKeyedCipher {
+    type BlockSize = U16;
+    type ParBlocks = U4;
+}
+
+impl FromKey for DummyKeyedCipher {
+    type KeySize = U16;
+
+    fn new(key: &GenericArray<u8, U16>) -> Self {
+        Self { key: *key }
+    }
+}
+
+impl BlockEncrypt for DummyKeyedCipher {
+    fn encrypt_block(&self, block: &mut Block<Self>) {
+        self.xor_key(block);
+        block.rotate_left(3);
+    }
+
+    fn encrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        blocks.iter_mut().for_each(|b| self.xor_key(b));
+        blocks.iter_mut().for_each(|b| b.rotate_left(3));
+    }
+}
+
+impl BlockDecrypt for DummyKeyedCipher {
+    fn decrypt_block(&self, block: &mut Block<Self>) {
+        block.rotate_right(3);
+        self.xor_key(block);
+    }
+
+    fn decrypt_par_blocks(&self, blocks: &mut ParBlocks<Self>) {
+        blocks.iter_mut().for_each(|b| b.rotate_right(3));
+        blocks.iter_mut().for_each(|b| self.xor_key(b));
+    }
+}
+
+/// Dummy CTR mode encryptor with a 128-bit big endian counter.
+#[derive(Debug, PartialEq)]
+pub struct DummyCtr {
+    pub cipher: DummyKeyedCipher,
+    pub ctr: u128,
+}
+
+impl FromKeyNonce for DummyCtr {
+    type KeySize = U16;
+    type NonceSize = U16;
+
+    fn new(key: &GenericArray<u8, U16>, nonce: &GenericArray<u8, U16>) -> Self {
+        let mut res = Self {
+            cipher: DummyKeyedCipher::new(key),
+            ctr: 0,
+        };
+        res.reinit(nonce);
+        res
+    }
+}
+
+impl BlockMode for DummyCtr {
+    type BlockSize = U16;
+}
+
+impl BlockModeEncrypt for DummyCtr {
+    fn encrypt_blocks(&mut self, blocks: &mut [GenericArray<u8, U16>]) {
+        for block in blocks {
+            let mut ks = GenericArray::from(self.ctr.to_be_bytes());
+            self.cipher.encrypt_block(&mut ks);
+            block.iter_mut().zip(ks.iter()).for_each(|(a, b)| *a ^= b);
+            self.ctr = self.ctr.wrapping_add(1);
+        }
+    }
+}
+
+impl BlockModeReinit for DummyCtr {
+    fn reinit(&mut self, iv: &GenericArray<u8, U16>) {
+        self.ctr = u128::from_be_bytes((*iv).into());
+    }
+}
+
+impl BlockModeIvState<DummyKeyedCipher> for DummyCtr {
+    fn iv_state(&self) -> GenericArray<u8, U16> {
+        self.ctr.to_be_bytes().into()
+    }
+}
+
+/// Dummy seekable stream cipher with finite keystream derived from
+/// the byte position.
+#[derive(Clone, Debug, Default)]
+pub struct DummyStreamCipher {
+    pub pos: u64,
+}
+
+impl FromKeyNonce for DummyStreamCipher {
+    type KeySize = U16;
+    type NonceSize = U16;
+
+    fn new(_key: &GenericArray<u8, U16>, _nonce: &GenericArray<u8, U16>) -> Self {
+        Self::default()
+    }
+}
+
+impl StreamCipher for DummyStreamCipher {
+    fn try_apply_keystream(&mut self, data: &mut [u8]) -> Result<(), LoopError> {
+        if self.pos + data.len() as u64 > KEYSTREAM_LEN {
+            return Err(LoopError);
+        }
+        for b in data.iter_mut() {
+            *b ^= (self.pos.wrapping_mul(0x9E37_79B9) >> 8) as u8;
+            self.pos += 1;
+        }
+        Ok(())
+    }
+}
+
+impl StreamCipherSeek for DummyStreamCipher {
+    fn try_current_pos<T: SeekNum>(&self) -> Result<T, OverflowError> {
+        T::try_from(self.pos).map_err(|_| OverflowError)
+    }
+
+    fn try_seek<T: SeekNum>(&mut self, pos: T) -> Result<(), LoopError> {
+        let pos = pos.try_into().map_err(|_| LoopError)?;
+        if pos > KEYSTREAM_LEN {
+            return Err(LoopError);
+        }
+        self.pos = pos;
+        Ok(())
+    }
+}
+
+impl StreamCipherCapabilities for DummyStreamCipher {
+    const IS_SEEKABLE: bool = true;
+}
@@ -0,0 +1,29 @@
+use cipher::errors::{
+    BlockModeError, CapacityError, InvalidLength, LoopError, NotEqualError, OverflowError, PadError,
+};
+use core::error::Error;
+
+fn check(err: &dyn Error, msg: &str) {
+    assert_eq!(err.to_string(), msg);
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn core_error() {
+    check(&LoopError, "Loop Error");
+    check(&OverflowError, "Overflow Error");
+    check(&PadError, "Padding Error");
+    check(&CapacityError, "Capacity Error");
+    check(&InvalidLength, "Invalid Length");
+    assert!(!NotEqualError.to_string().is_empty());
+    assert!(!BlockModeError.to_string().is_empty());
+}
+
+#[test]
+fn question_mark() {
+    fn f() -> Result<(), Box<dyn Error>> {
+        Err(InvalidLength)?;
+        Ok(())
+    }
+    assert!(f().is_err());
+}
@@ -0,0 +1,24 @@
+//! Build-only test: calling `defmt::Format::format` requires a global
+//! `defmt` logger which is not available on the host.
+
+use cipher::errors::{
+    BlockError, BlockModeError, CapacityError, CipherError, InvalidLength, KeyWrapError, LoopError,
+    NotEqualError, OverflowError, PadError, SelfTestError,
+};
+
+fn assert_format<T: defmt::Format>() {}
+
+#[test]
+fn errors_impl_format() {
+    assert_format::<LoopError>();
+    assert_format::<OverflowError>();
+    assert_format::<NotEqualError>();
+    assert_format::<PadError>();
+    assert_format::<CapacityError>();
+    assert_format::<InvalidLength>();
+    assert_format::<BlockModeError>();
+    assert_format::<SelfTestError>();
+    assert_format::<BlockError>();
+    assert_format::<CipherError>();
+    assert_format::<KeyWrapError>();
+}
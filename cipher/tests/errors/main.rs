@@ -0,0 +1,5 @@
+//! Tests for error types.
+
+mod core_error;
+#[cfg(feature = "defmt")]
+mod defmt;
@@ -0,0 +1,32 @@
+use cipher::errors::InvalidLength;
+use cipher::{BlockModeIvState, FromKey, FromKeyNonce};
+
+use crate::common::{DummyCtr, DummyKeyedCipher};
+
+#[test]
+fn new_from_slice() {
+    let key = [0x42; 16];
+    let cipher = DummyKeyedCipher::new_from_slice(&key).unwrap();
+    assert_eq!(cipher.key.as_slice(), &key);
+
+    for len in [0, 15, 17, 32] {
+        let res = DummyKeyedCipher::new_from_slice(&vec![0; len]);
+        assert_eq!(res, Err(InvalidLength));
+    }
+}
+
+#[test]
+fn new_from_slices() {
+    let key = [0x42; 16];
+    let nonce = [0x24; 16];
+    let mode = DummyCtr::new_from_slices(&key, &nonce).unwrap();
+    assert_eq!(mode.cipher.key.as_slice(), &key);
+    assert_eq!(mode.iv_state().as_slice(), &nonce);
+
+    let res = DummyCtr::new_from_slices(&key[..15], &nonce);
+    assert_eq!(res, Err(InvalidLength));
+    let res = DummyCtr::new_from_slices(&key, &[0; 17]);
+    assert_eq!(res, Err(InvalidLength));
+    let res = DummyCtr::new_from_slices(&[], &[]);
+    assert_eq!(res, Err(InvalidLength));
+}
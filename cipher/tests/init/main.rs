@@ -0,0 +1,8 @@
+//! Tests for key and nonce initialization.
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod from_slice;
+#[cfg(feature = "rand_core")]
+mod rand;
@@ -0,0 +1,53 @@
+use cipher::rand_core::{impls, CryptoRng, Error, RngCore};
+use cipher::{FromKey, FromKeyNonce};
+
+use crate::common::{DummyCtr, DummyKeyedCipher};
+
+/// Deterministic RNG which returns incrementing bytes starting from the seed.
+struct CounterRng(u8);
+
+impl RngCore for CounterRng {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for b in dest.iter_mut() {
+            *b = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for CounterRng {}
+
+#[test]
+fn generate_key() {
+    let key = <DummyKeyedCipher as FromKey>::generate_key(CounterRng(7));
+    assert_eq!(key.len(), 16);
+    assert_eq!(
+        key,
+        <DummyKeyedCipher as FromKey>::generate_key(CounterRng(7))
+    );
+    let expected: Vec<u8> = (7..23).collect();
+    assert_eq!(key.as_slice(), &expected[..]);
+}
+
+#[test]
+fn generate_key_nonce() {
+    let (key, nonce) = DummyCtr::generate_key_nonce(CounterRng(0));
+    assert_eq!(key.len(), 16);
+    assert_eq!(nonce.len(), 16);
+    let expected: Vec<u8> = (0..32).collect();
+    assert_eq!(key.as_slice(), &expected[..16]);
+    assert_eq!(nonce.as_slice(), &expected[16..]);
+}
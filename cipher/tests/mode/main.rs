@@ -0,0 +1,10 @@
+//! Tests for block mode traits.
+
+#[path = "../common/mod.rs"]
+mod common;
+
+mod reinit;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "zeroize")]
+mod zeroize;
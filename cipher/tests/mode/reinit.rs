@@ -0,0 +1,139 @@
+use cipher::consts::U16;
+use cipher::generic_array::GenericArray;
+use cipher::{
+    Block, BlockCipher, BlockEncrypt, BlockMode, BlockModeEncrypt, BlockModeIvState,
+    BlockModeReinit, FromKey, FromKeyNonce,
+};
+
+use crate::common::{DummyCtr, DummyKeyedCipher};
+
+type Key = GenericArray<u8, U16>;
+type Iv = GenericArray<u8, U16>;
+
+/// Dummy CBC mode encryptor.
+struct DummyCbc {
+    cipher: DummyKeyedCipher,
+    iv: GenericArray<u8, U16>,
+}
+
+impl FromKeyNonce for DummyCbc {
+    type KeySize = U16;
+    type NonceSize = U16;
+
+    fn new(key: &GenericArray<u8, U16>, nonce: &GenericArray<u8, U16>) -> Self {
+        Self {
+            cipher: DummyKeyedCipher::new(key),
+            iv: *nonce,
+        }
+    }
+}
+
+impl BlockMode for DummyCbc {
+    type BlockSize = U16;
+}
+
+impl BlockModeEncrypt for DummyCbc {
+    fn encrypt_blocks(&mut self, blocks: &mut [GenericArray<u8, U16>]) {
+        for block in blocks {
+            block
+                .iter_mut()
+                .zip(self.iv.iter())
+                .for_each(|(a, b)| *a ^= b);
+            self.cipher.encrypt_block(block);
+            self.iv = *block;
+        }
+    }
+}
+
+impl BlockModeReinit for DummyCbc {
+    fn reinit(&mut self, iv: &GenericArray<u8, U16>) {
+        self.iv = *iv;
+    }
+}
+
+impl BlockModeIvState<DummyKeyedCipher> for DummyCbc {
+    fn iv_state(&self) -> GenericArray<u8, U16> {
+        self.iv
+    }
+}
+
+fn blocks(n: u8) -> Vec<Block<DummyKeyedCipher>> {
+    (1..=n).map(|i| [i; 16].into()).collect()
+}
+
+/// Encrypt records with one mode instance, reinitializing it with the
+/// record IV before each record.
+fn encrypt_records<M>(mode: &mut M, records: &mut [(Iv, Vec<Block<DummyKeyedCipher>>)])
+where
+    M: BlockModeReinit<NonceSize = U16> + BlockModeEncrypt<BlockSize = U16>,
+{
+    for (iv, blocks) in records.iter_mut() {
+        mode.reinit(iv);
+        mode.encrypt_blocks(blocks);
+    }
+}
+
+/// Check that a reinitialized mode behaves as a freshly created one.
+fn check_reinit<M>()
+where
+    M: BlockModeReinit<KeySize = U16, NonceSize = U16> + BlockModeEncrypt<BlockSize = U16>,
+{
+    let key = Key::from([0x42; 16]);
+    let mut records: Vec<(Iv, _)> = (0..4u8).map(|i| ([i; 16].into(), blocks(i + 1))).collect();
+
+    let mut expected = records.clone();
+    for (iv, blocks) in expected.iter_mut() {
+        M::new(&key, iv).encrypt_blocks(blocks);
+    }
+
+    let mut mode = M::new(&key, &[0xAA; 16].into());
+    mode.encrypt_blocks(&mut blocks(3));
+    encrypt_records(&mut mode, &mut records);
+    assert_eq!(records, expected);
+}
+
+/// Check that processing resumed from a captured IV state continues
+/// exactly where it was captured, both after restoring the state of the
+/// same instance and in a new instance created with the state as IV.
+fn check_restore<C, M>()
+where
+    C: BlockCipher,
+    M: BlockModeIvState<C, KeySize = U16, NonceSize = U16>
+        + BlockModeReinit
+        + BlockModeEncrypt<BlockSize = U16>,
+{
+    let key = Key::from([0x42; 16]);
+    let iv = Iv::from([0xAA; 16]);
+    let pt = blocks(5);
+
+    for split in 0..pt.len() {
+        let mut expected = pt.clone();
+        M::new(&key, &iv).encrypt_blocks(&mut expected);
+
+        let mut mode = M::new(&key, &iv);
+        let mut ct = pt.clone();
+        mode.encrypt_blocks(&mut ct[..split]);
+        let state = mode.iv_state();
+
+        let mut fresh = ct.clone();
+        M::new(&key, &state).encrypt_blocks(&mut fresh[split..]);
+        assert_eq!(fresh, expected);
+
+        mode.encrypt_blocks(&mut blocks(2));
+        mode.restore_iv_state(&state);
+        mode.encrypt_blocks(&mut ct[split..]);
+        assert_eq!(ct, expected);
+    }
+}
+
+#[test]
+fn reinit_matches_new() {
+    check_reinit::<DummyCbc>();
+    check_reinit::<DummyCtr>();
+}
+
+#[test]
+fn restore_iv_state() {
+    check_restore::<DummyKeyedCipher, DummyCbc>();
+    check_restore::<DummyKeyedCipher, DummyCtr>();
+}
@@ -0,0 +1,101 @@
+use cipher::generic_array::GenericArray;
+use cipher::{Block, BlockModeEncrypt, BlockModeIvState, FromKeyNonce};
+
+use crate::common::{DummyCtr, DummyKeyedCipher};
+
+const KEY: [u8; 16] = [0x42; 16];
+const IV: [u8; 16] = [0xAA; 16];
+
+fn blocks(n: u8) -> Vec<Block<DummyKeyedCipher>> {
+    (1..=n).map(|i| [i; 16].into()).collect()
+}
+
+/// Encrypt the first `split` blocks, serialize the IV state with `ser`,
+/// then resume encryption of the remaining blocks from the deserialized
+/// state in a new instance.
+fn resume<E, F>(ser: impl Fn(&DummyCtr) -> E, de: F)
+where
+    F: Fn(&E) -> GenericArray<u8, <DummyCtr as FromKeyNonce>::NonceSize>,
+{
+    let pt = blocks(5);
+    let mut expected = pt.clone();
+    DummyCtr::new(&KEY.into(), &IV.into()).encrypt_blocks(&mut expected);
+
+    for split in 0..pt.len() {
+        let mut mode = DummyCtr::new(&KEY.into(), &IV.into());
+        let mut ct = pt.clone();
+        mode.encrypt_blocks(&mut ct[..split]);
+
+        let state = de(&ser(&mode));
+        assert_eq!(state, mode.iv_state());
+
+        DummyCtr::new(&KEY.into(), &state).encrypt_blocks(&mut ct[split..]);
+        assert_eq!(ct, expected);
+    }
+}
+
+#[test]
+fn serde_json_roundtrip() {
+    resume(
+        |mode| {
+            let mut buf = Vec::new();
+            mode.serialize_iv_state(&mut serde_json::Serializer::new(&mut buf))
+                .unwrap();
+            buf
+        },
+        |buf| {
+            DummyCtr::deserialize_iv_state(&mut serde_json::Deserializer::from_slice(buf)).unwrap()
+        },
+    );
+}
+
+#[test]
+fn bincode_roundtrip() {
+    resume(
+        |mode| {
+            let mut buf = Vec::new();
+            mode.serialize_iv_state(&mut bincode::Serializer::new(
+                &mut buf,
+                bincode::DefaultOptions::new(),
+            ))
+            .unwrap();
+            buf
+        },
+        |buf| {
+            DummyCtr::deserialize_iv_state(&mut bincode::Deserializer::from_slice(
+                buf,
+                bincode::DefaultOptions::new(),
+            ))
+            .unwrap()
+        },
+    );
+}
+
+#[test]
+fn fixed_length_array() {
+    let mode = DummyCtr::new(&KEY.into(), &[7; 16].into());
+
+    let mut json = Vec::new();
+    mode.serialize_iv_state(&mut serde_json::Serializer::new(&mut json))
+        .unwrap();
+    assert_eq!(json, b"[7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7]");
+
+    // no length prefix is written for fixed-length arrays
+    let mut bin = Vec::new();
+    mode.serialize_iv_state(&mut bincode::Serializer::new(
+        &mut bin,
+        bincode::DefaultOptions::new(),
+    ))
+    .unwrap();
+    assert_eq!(bin, [7; 16]);
+
+    for json in [&b"[7,7,7]"[..], b"[7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7]"] {
+        let res = DummyCtr::deserialize_iv_state(&mut serde_json::Deserializer::from_slice(json));
+        assert!(res.is_err());
+    }
+    let res = DummyCtr::deserialize_iv_state(&mut bincode::Deserializer::from_slice(
+        &bin[..15],
+        bincode::DefaultOptions::new(),
+    ));
+    assert!(res.is_err());
+}
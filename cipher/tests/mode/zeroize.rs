@@ -0,0 +1,66 @@
+use cipher::consts::U16;
+use cipher::generic_array::GenericArray;
+use cipher::zeroize::{Zeroize, ZeroizeOnDrop};
+use cipher::{BlockModeIvState, FromKeyNonce, ZeroizingMode};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::common::DummyCtr;
+
+impl Zeroize for DummyCtr {
+    fn zeroize(&mut self) {
+        self.cipher.key.zeroize();
+        self.ctr.zeroize();
+    }
+}
+
+/// Dummy mode which records its IV after being zeroized.
+struct LoggedMode {
+    iv: GenericArray<u8, U16>,
+    log: Rc<RefCell<Vec<GenericArray<u8, U16>>>>,
+}
+
+impl Zeroize for LoggedMode {
+    fn zeroize(&mut self) {
+        self.iv.zeroize();
+        self.log.borrow_mut().push(self.iv);
+    }
+}
+
+fn zeroize_on_drop<T: ZeroizeOnDrop>(_: &T) {}
+
+#[test]
+fn iv_state_zeroizing() {
+    let nonce = GenericArray::from([0xAB; 16]);
+    let mode = DummyCtr::new(&Default::default(), &nonce);
+
+    let mut iv = mode.iv_state_zeroizing();
+    assert_eq!(*iv, nonce);
+    iv.zeroize();
+    assert_eq!(*iv, GenericArray::<u8, U16>::default());
+}
+
+#[test]
+fn zeroizing_mode_drop() {
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mode = ZeroizingMode::from_mode(LoggedMode {
+        iv: GenericArray::from([0xAB; 16]),
+        log: log.clone(),
+    });
+    zeroize_on_drop(&mode);
+    assert_eq!(mode.iv, GenericArray::from([0xAB; 16]));
+    assert!(log.borrow().is_empty());
+
+    drop(mode);
+    assert_eq!(*log.borrow(), [GenericArray::<u8, U16>::default()]);
+}
+
+#[test]
+fn zeroizing_mode_from_key_nonce() {
+    let nonce = GenericArray::from([0xAB; 16]);
+    let mut mode = ZeroizingMode::<DummyCtr>::new(&Default::default(), &nonce);
+    assert_eq!(mode.iv_state(), nonce);
+
+    mode.zeroize();
+    assert_eq!(mode.iv_state(), GenericArray::<u8, U16>::default());
+}
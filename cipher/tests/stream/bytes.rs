@@ -0,0 +1,70 @@
+use bytes::BytesMut;
+use cipher::StreamCipher;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+fn expected(pt: &[u8]) -> Vec<u8> {
+    let mut buf = pt.to_vec();
+    DummyStreamCipher::default().apply_keystream(&mut buf);
+    buf
+}
+
+#[test]
+fn bytes_mut() {
+    let pt: Vec<u8> = (0..100).collect();
+    let mut buf = BytesMut::from(&pt[..]);
+    DummyStreamCipher::default().apply_keystream_bytes(&mut buf);
+    assert_eq!(buf[..], expected(&pt)[..]);
+
+    let mut cipher = DummyStreamCipher::default();
+    let mut buf = BytesMut::from(&pt[..]);
+    <&mut DummyStreamCipher as StreamCipher>::apply_keystream_bytes(&mut &mut cipher, &mut buf);
+    assert_eq!(buf[..], expected(&pt)[..]);
+    assert_eq!(cipher.pos, 100);
+}
+
+#[test]
+fn split_to() {
+    let pt: Vec<u8> = (0..100).collect();
+    let expected = expected(&pt);
+
+    let mut cipher = DummyStreamCipher::default();
+    let mut buf = BytesMut::from(&pt[..]);
+    let mut out = Vec::new();
+    for &n in &[0, 1, 15, 17, 40] {
+        let chunk = cipher.apply_keystream_split_to(&mut buf, n);
+        assert_eq!(chunk.len(), n);
+        assert_eq!(buf[..], pt[out.len() + n..]);
+        out.extend_from_slice(&chunk);
+    }
+    let n = buf.len();
+    let chunk = cipher.try_apply_keystream_split_to(&mut buf, n).unwrap();
+    out.extend_from_slice(&chunk);
+    assert!(buf.is_empty());
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn split_to_end_of_keystream() {
+    let pt = vec![0x42u8; KEYSTREAM_LEN as usize + 10];
+    let mut buf = BytesMut::from(&pt[..]);
+    let mut cipher = DummyStreamCipher::default();
+    assert!(cipher
+        .try_apply_keystream_split_to(&mut buf, KEYSTREAM_LEN as usize + 1)
+        .is_err());
+    assert_eq!(buf[..], pt[..]);
+    assert_eq!(cipher.pos, 0);
+}
+
+#[test]
+fn split_to_out_of_bounds() {
+    let mut buf = BytesMut::from(&[0x42u8; 10][..]);
+    let mut cipher = DummyStreamCipher::default();
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        cipher.apply_keystream_split_to(&mut buf, 11)
+    }));
+    assert!(res.is_err());
+    assert_eq!(buf[..], [0x42; 10]);
+    assert_eq!(cipher.pos, 0);
+}
@@ -0,0 +1,23 @@
+use cipher::{StreamCipher, StreamCipherCheckpoint};
+
+use crate::common::DummyStreamCipher;
+
+#[test]
+fn checkpoint_restore() {
+    let mut cipher = DummyStreamCipher::default();
+    let mut buf = [0u8; 100];
+    cipher.apply_keystream(&mut buf[..30]);
+
+    let checkpoint = cipher.checkpoint();
+    let mut ks1 = [0u8; 70];
+    cipher.write_keystream(&mut ks1);
+
+    cipher.restore(checkpoint);
+    let mut ks2 = [0u8; 70];
+    cipher.write_keystream(&mut ks2);
+    assert_eq!(ks1, ks2);
+
+    let mut expected = [0u8; 100];
+    DummyStreamCipher::default().write_keystream(&mut expected);
+    assert_eq!(&ks1[..], &expected[30..]);
+}
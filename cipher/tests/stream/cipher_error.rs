@@ -0,0 +1,19 @@
+use cipher::errors::CipherError;
+use cipher::{FromKeyNonce, StreamCipher};
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+fn encrypt(key: &[u8], data: &mut [u8]) -> Result<(), CipherError> {
+    let mut cipher = DummyStreamCipher::new_from_slices(key, &[0; 16])?;
+    cipher.try_apply_keystream(data)?;
+    Ok(())
+}
+
+#[test]
+fn cipher_error() {
+    let mut buf = vec![0; KEYSTREAM_LEN as usize + 1];
+    let (fits, _) = buf.split_at_mut(KEYSTREAM_LEN as usize);
+    assert_eq!(encrypt(&[0; 16], fits), Ok(()));
+    assert_eq!(encrypt(&[0; 15], fits), Err(CipherError::InvalidLength));
+    assert_eq!(encrypt(&[0; 16], &mut buf), Err(CipherError::Loop));
+}
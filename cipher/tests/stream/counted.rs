@@ -0,0 +1,20 @@
+use cipher::StreamCipher;
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+#[test]
+fn counted() {
+    let mut cipher = DummyStreamCipher::default();
+    let mut expected = [0u8; 40];
+    DummyStreamCipher::default().apply_keystream(&mut expected);
+
+    let mut buf = [0u8; 40];
+    assert_eq!(cipher.apply_keystream_counted(&mut buf[..0]).unwrap(), 0);
+    assert_eq!(cipher.apply_keystream_counted(&mut buf[..15]).unwrap(), 15);
+    assert_eq!(cipher.apply_keystream_counted(&mut buf[15..]).unwrap(), 25);
+    assert_eq!(buf, expected);
+
+    let mut buf = vec![0u8; KEYSTREAM_LEN as usize - 39];
+    assert!(cipher.apply_keystream_counted(&mut buf).is_err());
+    assert!(buf.iter().all(|&b| b == 0));
+}
@@ -0,0 +1,99 @@
+use cipher::consts::U16;
+use cipher::generic_array::GenericArray;
+use cipher::{
+    BlockEncrypt, BlockModeEncrypt, Ctr, Ctr128BE, Ctr32BE, Ctr64BE, CtrFlavor, FromKey,
+    FromKeyNonce, StreamCipher, StreamCipherSeek,
+};
+
+use crate::common::{DummyCtr, DummyKeyedCipher};
+
+const KEY: [u8; 16] = [0x11; 16];
+
+/// Initial counter block with the given counter bytes at its end.
+fn nonce(ctr: &[u8]) -> GenericArray<u8, U16> {
+    let mut res = GenericArray::from([0xA5; 16]);
+    res[16 - ctr.len()..].copy_from_slice(ctr);
+    res
+}
+
+fn keystream<F: CtrFlavor>(nonce: &GenericArray<u8, U16>, len: usize) -> Vec<u8> {
+    let mut buf = vec![0u8; len];
+    Ctr::<DummyKeyedCipher, F>::new(&KEY.into(), nonce).apply_keystream(&mut buf);
+    buf
+}
+
+/// Keystream block for the given counter block.
+fn ks_block(ctr_block: GenericArray<u8, U16>) -> Vec<u8> {
+    let mut block = ctr_block;
+    DummyKeyedCipher::new(&KEY.into()).encrypt_block(&mut block);
+    block.to_vec()
+}
+
+#[test]
+fn ctr128_matches_block_mode() {
+    let nonce = nonce(&[0x01, 0x02, 0xFF, 0xFF, 0xFF, 0xFE]);
+    let mut blocks = vec![GenericArray::default(); 11];
+    DummyCtr::new(&KEY.into(), &nonce).encrypt_blocks(&mut blocks);
+    let expected: Vec<u8> = blocks.iter().flatten().copied().collect();
+    assert_eq!(keystream::<Ctr128BE>(&nonce, expected.len()), expected);
+}
+
+#[test]
+fn chunked() {
+    let nonce = nonce(&[0x42]);
+    let expected = keystream::<Ctr64BE>(&nonce, 300);
+    for &chunk_size in &[1, 7, 15, 16, 17, 63, 64, 65, 100] {
+        let mut cipher = Ctr::<DummyKeyedCipher, Ctr64BE>::new(&KEY.into(), &nonce);
+        let mut buf = vec![0u8; expected.len()];
+        for chunk in buf.chunks_mut(chunk_size) {
+            cipher.apply_keystream(chunk);
+        }
+        assert_eq!(buf, expected, "chunk size: {}", chunk_size);
+    }
+}
+
+#[test]
+fn counter_wraps_within_width() {
+    let ctr = [0xFF; 4];
+    let ks = keystream::<Ctr32BE>(&nonce(&ctr), 32);
+    assert_eq!(ks[..16], ks_block(nonce(&ctr))[..]);
+    assert_eq!(ks[16..], ks_block(nonce(&[0; 4]))[..]);
+
+    let ctr = [0xFF; 8];
+    let ks = keystream::<Ctr64BE>(&nonce(&ctr), 32);
+    assert_eq!(ks[16..], ks_block(nonce(&[0; 8]))[..]);
+}
+
+#[test]
+fn seek() {
+    let nonce = nonce(&[0x42]);
+    let expected = keystream::<Ctr32BE>(&nonce, 300);
+    let mut cipher = Ctr::<DummyKeyedCipher, Ctr32BE>::new(&KEY.into(), &nonce);
+    for &pos in &[0usize, 1, 15, 16, 17, 63, 64, 65, 100, 299] {
+        cipher.seek(pos);
+        assert_eq!(cipher.current_pos::<usize>(), pos);
+        let mut buf = vec![0u8; 300 - pos];
+        cipher.apply_keystream(&mut buf);
+        assert_eq!(buf, expected[pos..], "position: {}", pos);
+        assert_eq!(cipher.current_pos::<u64>(), 300);
+    }
+}
+
+#[test]
+fn end_of_keystream() {
+    let iv = nonce(&[0; 4]);
+    let end = 16u64 << 32;
+    let mut cipher = Ctr::<DummyKeyedCipher, Ctr32BE>::new(&KEY.into(), &iv);
+    assert!(cipher.try_seek(end + 1).is_err());
+
+    cipher.seek(end - 8);
+    let mut buf = [0u8; 9];
+    assert!(cipher.try_apply_keystream(&mut buf).is_err());
+    assert_eq!(buf, [0; 9]);
+    cipher.apply_keystream(&mut buf[..8]);
+    assert_eq!(cipher.current_pos::<u64>(), end);
+    assert!(cipher.try_apply_keystream(&mut buf[..1]).is_err());
+
+    // the last block uses counter 0xFFFFFFFF
+    assert_eq!(buf[..8], ks_block(nonce(&[0xFF; 4]))[8..]);
+}
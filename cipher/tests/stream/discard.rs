@@ -0,0 +1,29 @@
+use cipher::{StreamCipher, StreamCipherSeek};
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+#[test]
+fn discard_matches_seek() {
+    for &n in &[0, 1, 63, 64, 65, 500, 999] {
+        let mut c1 = DummyStreamCipher::default();
+        c1.discard_keystream(n);
+        let mut c2 = DummyStreamCipher::default();
+        c2.seek(n);
+
+        let mut ks1 = [0u8; 1];
+        let mut ks2 = [0u8; 1];
+        c1.write_keystream(&mut ks1);
+        c2.write_keystream(&mut ks2);
+        assert_eq!(ks1, ks2, "n: {}", n);
+    }
+}
+
+#[test]
+fn discard_end() {
+    let mut cipher = DummyStreamCipher::default();
+    cipher.discard_keystream(KEYSTREAM_LEN as usize);
+    assert!(cipher.try_discard_keystream(1).is_err());
+    assert!(DummyStreamCipher::default()
+        .try_discard_keystream(1001)
+        .is_err());
+}
@@ -0,0 +1,40 @@
+use cipher::errors::LoopError;
+use cipher::{NonceMisuseGuard, PrefetchStream, StreamCipher, StreamCipherCapabilities};
+
+use crate::common::DummyStreamCipher;
+
+/// Dummy non-seekable stream cipher.
+struct Plain;
+
+impl StreamCipher for Plain {
+    fn try_apply_keystream(&mut self, _: &mut [u8]) -> Result<(), LoopError> {
+        Ok(())
+    }
+}
+
+impl StreamCipherCapabilities for Plain {}
+
+fn is_seekable<C: StreamCipherCapabilities>() -> bool {
+    C::IS_SEEKABLE
+}
+
+#[test]
+fn is_seekable_flag() {
+    assert!(!is_seekable::<Plain>());
+    assert!(is_seekable::<DummyStreamCipher>());
+    assert!(!is_seekable::<&mut Plain>());
+    assert!(is_seekable::<&mut DummyStreamCipher>());
+    assert!(!is_seekable::<PrefetchStream<Plain, 16>>());
+    assert!(is_seekable::<PrefetchStream<DummyStreamCipher, 16>>());
+    assert!(is_seekable::<NonceMisuseGuard<DummyStreamCipher>>());
+}
+
+#[test]
+fn stream_cipher_trait_object() {
+    let mut seekable = DummyStreamCipher::default();
+    let ciphers: [&mut dyn StreamCipher; 2] = [&mut Plain, &mut seekable];
+    for cipher in ciphers {
+        cipher.apply_keystream(&mut [0u8; 10]);
+    }
+    assert_eq!(seekable.pos, 10);
+}
@@ -0,0 +1,64 @@
+use cipher::errors::CipherError;
+use cipher::StreamCipher;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+#[test]
+fn b2b() {
+    let pt: Vec<u8> = (0..100).collect();
+    let mut expected = pt.clone();
+    DummyStreamCipher::default().apply_keystream(&mut expected);
+
+    let mut out = vec![0u8; pt.len()];
+    DummyStreamCipher::default().apply_keystream_b2b(&pt, &mut out);
+    assert_eq!(out, expected);
+
+    let mut out = vec![0u8; pt.len()];
+    DummyStreamCipher::default()
+        .try_apply_keystream_b2b(&pt, &mut out)
+        .unwrap();
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn b2b_len_mismatch() {
+    let pt = [0x42u8; 10];
+    let mut out = [0xFFu8; 11];
+    let mut cipher = DummyStreamCipher::default();
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        cipher.apply_keystream_b2b(&pt, &mut out)
+    }));
+    assert!(res.is_err());
+    assert_eq!(out, [0xFF; 11]);
+    assert_eq!(
+        cipher.try_apply_keystream_b2b(&pt, &mut out),
+        Err(CipherError::NotEqual)
+    );
+    assert_eq!(out, [0xFF; 11]);
+    assert_eq!(cipher.pos, 0);
+}
+
+#[test]
+fn try_b2b_end_of_keystream() {
+    let pt = vec![0x42u8; KEYSTREAM_LEN as usize + 1];
+    let mut out = vec![0xFFu8; pt.len()];
+    let mut cipher = DummyStreamCipher::default();
+    assert_eq!(
+        cipher.try_apply_keystream_b2b(&pt, &mut out),
+        Err(CipherError::Loop)
+    );
+    assert!(out.iter().all(|&b| b == 0));
+    assert_eq!(cipher.pos, 0);
+}
+
+#[test]
+fn b2b_panic_clears_output() {
+    let pt = vec![0x42u8; KEYSTREAM_LEN as usize + 1];
+    let mut out = vec![0xFFu8; pt.len()];
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        DummyStreamCipher::default().apply_keystream_b2b(&pt, &mut out)
+    }));
+    assert!(res.is_err());
+    assert!(out.iter().all(|&b| b == 0));
+}
@@ -0,0 +1,27 @@
+use cipher::StreamCipher;
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+#[test]
+fn keystream_iter() {
+    let mut ks = [0u8; 300];
+    DummyStreamCipher::default().write_keystream(&mut ks);
+
+    let res: Vec<u8> = DummyStreamCipher::default()
+        .keystream_iter()
+        .take(300)
+        .collect();
+    assert_eq!(res, &ks[..]);
+}
+
+#[test]
+fn keystream_iter_end() {
+    let mut ks = [0u8; KEYSTREAM_LEN as usize];
+    DummyStreamCipher::default().write_keystream(&mut ks);
+
+    let mut iter = DummyStreamCipher::default().keystream_iter();
+    let res: Vec<u8> = iter.by_ref().collect();
+    assert_eq!(res, &ks[..]);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.into_inner().pos, KEYSTREAM_LEN);
+}
@@ -0,0 +1,28 @@
+//! Tests for stream cipher traits and wrappers.
+
+#[path = "../common/mod.rs"]
+mod common;
+
+#[cfg(feature = "bytes")]
+mod bytes;
+mod checkpoint;
+mod cipher_error;
+mod counted;
+mod ctr;
+mod discard;
+mod is_seekable;
+mod keystream_b2b;
+mod keystream_iter;
+mod misuse_guard;
+mod overlapping;
+mod peek;
+mod prefetch;
+mod seek_num;
+#[cfg(feature = "async")]
+mod stream_async;
+mod stream_fmt;
+#[cfg(feature = "std")]
+mod stream_io;
+#[cfg(feature = "std")]
+mod vectored;
+mod write_keystream;
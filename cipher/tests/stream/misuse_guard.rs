@@ -0,0 +1,47 @@
+use cipher::{NonceMisuseGuard, StreamCipher, StreamCipherSeek};
+
+use crate::common::DummyStreamCipher;
+
+#[test]
+fn forward_use() {
+    let mut buf = [0u8; 64];
+    let mut expected = buf;
+    DummyStreamCipher::default().apply_keystream(&mut expected);
+
+    let mut guard = NonceMisuseGuard::new(DummyStreamCipher::default());
+    guard.apply_keystream(&mut buf[..10]);
+    guard.apply_keystream(&mut []);
+    guard.seek(10u32);
+    guard.apply_keystream(&mut buf[10..]);
+    assert_eq!(buf, expected);
+    assert_eq!(guard.current_pos::<u64>(), 64);
+    assert_eq!(guard.into_inner().pos, 64);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "keystream reuse detected at position 16")]
+fn backward_seek_reuse() {
+    let mut buf = [0u8; 64];
+    let mut guard = NonceMisuseGuard::new(DummyStreamCipher::default());
+    guard.apply_keystream(&mut buf[..32]);
+    guard.seek(16u8);
+    guard.apply_keystream(&mut buf[..1]);
+}
+
+#[test]
+fn peek_then_apply() {
+    let mut expected = [0u8; 32];
+    DummyStreamCipher::default().apply_keystream(&mut expected);
+
+    let mut guard = NonceMisuseGuard::new(DummyStreamCipher::default());
+    let mut peeked = [0u8; 32];
+    guard.peek_keystream(&mut peeked);
+    assert_eq!(peeked, expected);
+    assert_eq!(guard.current_pos::<u64>(), 0);
+
+    let mut buf = [0u8; 32];
+    guard.apply_keystream(&mut buf);
+    assert_eq!(buf, expected);
+    assert_eq!(guard.current_pos::<u64>(), 32);
+}
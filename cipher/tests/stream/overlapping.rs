@@ -0,0 +1,25 @@
+use cipher::StreamCipher;
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+#[test]
+fn overlapping() {
+    let buf: Vec<u8> = (0..64).collect();
+    for (in_offset, out_offset) in [(0, 0), (0, 3), (3, 0), (5, 6), (6, 5), (0, 64), (7, 7)] {
+        let len = buf.len() - usize::max(in_offset, out_offset);
+        let mut expected = buf[in_offset..][..len].to_vec();
+        DummyStreamCipher::default().apply_keystream(&mut expected);
+
+        let mut res = buf.clone();
+        DummyStreamCipher::default().apply_keystream_overlapping(&mut res, in_offset, out_offset);
+        assert_eq!(res[out_offset..][..len], expected[..]);
+    }
+}
+
+#[test]
+fn overlapping_error() {
+    let mut buf = vec![0u8; KEYSTREAM_LEN as usize + 20];
+    let res = DummyStreamCipher::default().try_apply_keystream_overlapping(&mut buf, 0, 10);
+    assert!(res.is_err());
+    assert!(buf.iter().all(|&b| b == 0));
+}
@@ -0,0 +1,30 @@
+use cipher::{StreamCipher, StreamCipherSeek};
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+#[test]
+fn peek() {
+    let mut cipher = DummyStreamCipher::default();
+    cipher.seek(10u32);
+
+    let mut peeked = [0u8; 16];
+    cipher.peek_keystream(&mut peeked);
+    assert_eq!(cipher.current_pos::<u64>(), 10);
+
+    let mut ks = [0u8; 16];
+    cipher.write_keystream(&mut ks);
+    assert_eq!(peeked, ks);
+    assert_eq!(cipher.current_pos::<u64>(), 26);
+}
+
+#[test]
+fn peek_end() {
+    let mut cipher = DummyStreamCipher::default();
+    cipher.seek(KEYSTREAM_LEN - 4);
+
+    let mut buf = [0u8; 4];
+    assert!(cipher.try_peek_keystream(&mut buf).is_ok());
+    let mut buf = [0u8; 5];
+    assert!(cipher.try_peek_keystream(&mut buf).is_err());
+    assert_eq!(cipher.current_pos::<u64>(), KEYSTREAM_LEN - 4);
+}
@@ -0,0 +1,62 @@
+use cipher::{PrefetchStream, StreamCipher, StreamCipherSeek};
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+fn keystream() -> Vec<u8> {
+    let mut ks = vec![0u8; KEYSTREAM_LEN as usize];
+    DummyStreamCipher::default().write_keystream(&mut ks);
+    ks
+}
+
+#[test]
+fn prefetch_matches_unbuffered() {
+    let ks = keystream();
+    for &chunk_size in &[1, 3, 63, 64, 65, 200, 1000] {
+        let mut buf = vec![0u8; ks.len()];
+        let mut cipher = PrefetchStream::<_, 64>::new(DummyStreamCipher::default());
+        for chunk in buf.chunks_mut(chunk_size) {
+            cipher.apply_keystream(chunk);
+        }
+        assert_eq!(buf, ks, "chunk size: {}", chunk_size);
+        assert_eq!(cipher.current_pos::<u64>(), KEYSTREAM_LEN);
+        assert!(cipher.try_apply_keystream(&mut [0]).is_err());
+    }
+}
+
+#[test]
+fn prefetch_seek() {
+    let ks = keystream();
+    let mut cipher = PrefetchStream::<_, 64>::new(DummyStreamCipher::default());
+
+    let mut buf = [0u8; 10];
+    cipher.apply_keystream(&mut buf);
+    assert_eq!(cipher.current_pos::<u32>(), 10);
+    assert_eq!(cipher.into_inner().pos, 74);
+
+    let mut cipher = PrefetchStream::<_, 64>::new(DummyStreamCipher::default());
+    cipher.apply_keystream(&mut buf);
+    cipher.seek(500u32);
+    assert_eq!(cipher.current_pos::<u32>(), 500);
+    let mut buf = [0u8; 20];
+    cipher.apply_keystream(&mut buf);
+    assert_eq!(&buf[..], &ks[500..520]);
+
+    cipher.seek(5u8);
+    let mut buf = [0u8; 100];
+    cipher.apply_keystream(&mut buf);
+    assert_eq!(&buf[..], &ks[5..105]);
+}
+
+#[test]
+fn prefetch_end() {
+    let mut cipher = PrefetchStream::<_, 64>::new(DummyStreamCipher::default());
+    let mut buf = vec![0u8; 990];
+    cipher.apply_keystream(&mut buf);
+
+    let mut buf = [1u8; 11];
+    assert!(cipher.try_apply_keystream(&mut buf).is_err());
+    assert_eq!(buf, [1u8; 11]);
+    let mut buf = [0u8; 10];
+    cipher.apply_keystream(&mut buf);
+    assert_eq!(&buf[..], &keystream()[990..]);
+}
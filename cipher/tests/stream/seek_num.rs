@@ -0,0 +1,73 @@
+use cipher::errors::{LoopError, OverflowError};
+use cipher::{SeekNum, StreamCipherSeek};
+
+const BLOCK_SIZE: u8 = 64;
+
+/// Dummy seekable cipher which only tracks its keystream position.
+#[derive(Default)]
+struct DummySeek {
+    block: u64,
+    byte: u8,
+}
+
+impl StreamCipherSeek for DummySeek {
+    fn try_current_pos<T: SeekNum>(&self) -> Result<T, OverflowError> {
+        T::from_block_byte(self.block, self.byte, BLOCK_SIZE)
+    }
+
+    fn try_seek<T: SeekNum>(&mut self, pos: T) -> Result<(), LoopError> {
+        let (block, byte) = pos.to_block_byte(BLOCK_SIZE)?;
+        self.block = block;
+        self.byte = byte;
+        Ok(())
+    }
+}
+
+macro_rules! seek_num_test {
+    ($($name:ident: $t:ty,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut cipher = DummySeek::default();
+                assert_eq!(cipher.current_pos::<$t>(), 0);
+                for &pos in &[1, 63, 64, 65, 100, 127] {
+                    cipher.seek::<$t>(pos);
+                    assert_eq!(cipher.current_pos::<$t>(), pos);
+                    assert_eq!(cipher.block, pos as u64 / 64);
+                    assert_eq!(cipher.byte, (pos % 64) as u8);
+                }
+            }
+        )*
+    };
+}
+
+seek_num_test! {
+    seek_u8: u8,
+    seek_u16: u16,
+    seek_u32: u32,
+    seek_u64: u64,
+    seek_u128: u128,
+    seek_usize: usize,
+    seek_i8: i8,
+    seek_i16: i16,
+    seek_i32: i32,
+    seek_i64: i64,
+    seek_i128: i128,
+    seek_isize: isize,
+}
+
+#[test]
+fn seek_overflow() {
+    let mut cipher = DummySeek::default();
+    cipher.seek(1000u32);
+    assert!(cipher.try_current_pos::<u8>().is_err());
+    assert!(cipher.try_seek(-1i32).is_err());
+}
+
+#[test]
+fn seek_i8_big_block() {
+    assert_eq!(i8::from_block_byte(0u64, 100, 200).unwrap(), 100);
+    assert!(i8::from_block_byte(1u64, 0, 200).is_err());
+    assert_eq!(100i8.to_block_byte::<u64>(200).unwrap(), (0, 100));
+    assert!((-1i8).to_block_byte::<u64>(200).is_err());
+}
@@ -0,0 +1,114 @@
+use bytes::Bytes;
+use cipher::errors::LoopError;
+use cipher::{StreamCipher, StreamCipherSink, StreamCipherStream};
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::{stream, SinkExt, StreamExt, TryStreamExt};
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Loop,
+    Closed,
+}
+
+impl From<LoopError> for Error {
+    fn from(_: LoopError) -> Error {
+        Error::Loop
+    }
+}
+
+/// Chunks of different lengths which are not aligned to any block size.
+fn chunks(pt: &[u8]) -> Vec<Bytes> {
+    let mut res = Vec::new();
+    let mut rest = pt;
+    for n in (0..).map(|i| i % 19) {
+        if rest.is_empty() {
+            break;
+        }
+        let n = n.min(rest.len());
+        res.push(Bytes::copy_from_slice(&rest[..n]));
+        rest = &rest[n..];
+    }
+    res
+}
+
+#[test]
+fn sink() {
+    let pt: Vec<u8> = (0..500).map(|i| i as u8).collect();
+    let mut expected = pt.clone();
+    DummyStreamCipher::default().apply_keystream(&mut expected);
+
+    let inner = Vec::<Bytes>::new().sink_map_err(|err| -> Error { match err {} });
+    let mut sink = StreamCipherSink::new(DummyStreamCipher::default(), inner);
+    block_on(sink.send_all(&mut stream::iter(chunks(&pt)).map(Ok))).unwrap();
+    block_on(sink.close()).unwrap();
+
+    let (cipher, inner) = sink.into_inner();
+    let ct: Vec<u8> = inner.into_inner().concat();
+    assert_eq!(ct, expected);
+    assert_eq!(cipher.pos, pt.len() as u64);
+}
+
+#[test]
+fn stream() {
+    let pt: Vec<u8> = (0..500).map(|i| i as u8).collect();
+    let mut ct = pt.clone();
+    DummyStreamCipher::default().apply_keystream(&mut ct);
+
+    let inner = stream::iter(chunks(&ct)).map(Ok::<_, Error>);
+    let stream = StreamCipherStream::new(DummyStreamCipher::default(), inner);
+    let res: Vec<Bytes> = block_on(stream.try_collect()).unwrap();
+    assert_eq!(res, chunks(&pt));
+}
+
+#[test]
+fn shared_chunk_is_not_modified() {
+    let chunk = Bytes::from(vec![0x42u8; 32]);
+    let shared = chunk.clone();
+
+    let inner = Vec::<Bytes>::new().sink_map_err(|err| -> Error { match err {} });
+    let mut sink = StreamCipherSink::new(DummyStreamCipher::default(), inner);
+    block_on(sink.send(chunk)).unwrap();
+
+    assert_eq!(shared, [0x42u8; 32][..]);
+    assert_ne!(sink.get_ref().get_ref()[0], shared);
+}
+
+#[test]
+fn roundtrip_with_backpressure() {
+    let pt: Vec<u8> = (0..KEYSTREAM_LEN).map(|i| (i * 7) as u8).collect();
+    // channel with a single slot makes the sink wait until the receiving
+    // side consumes the previous chunk
+    let (tx, rx) = mpsc::channel::<Bytes>(0);
+
+    let tx = tx.sink_map_err(|_| Error::Closed);
+    let mut sink = StreamCipherSink::new(DummyStreamCipher::default(), tx);
+    let send = async {
+        sink.send_all(&mut stream::iter(chunks(&pt)).map(Ok))
+            .await
+            .unwrap();
+        sink.close().await.unwrap();
+    };
+
+    let stream = StreamCipherStream::new(DummyStreamCipher::default(), rx.map(Ok::<_, Error>));
+    let recv = stream.try_collect::<Vec<Bytes>>();
+
+    let ((), res) = block_on(futures::future::join(send, recv));
+    assert_eq!(res.unwrap().concat(), pt);
+}
+
+#[test]
+fn end_of_keystream() {
+    let inner = Vec::<Bytes>::new().sink_map_err(|err| -> Error { match err {} });
+    let mut sink = StreamCipherSink::new(DummyStreamCipher::default(), inner);
+    let chunk = Bytes::from(vec![0u8; KEYSTREAM_LEN as usize + 1]);
+    assert_eq!(block_on(sink.send(chunk)), Err(Error::Loop));
+    assert!(sink.get_ref().get_ref().is_empty());
+
+    let chunk = Bytes::from(vec![0u8; KEYSTREAM_LEN as usize + 1]);
+    let inner = stream::iter(vec![Ok::<_, Error>(chunk)]);
+    let mut stream = StreamCipherStream::new(DummyStreamCipher::default(), inner);
+    assert_eq!(block_on(stream.next()), Some(Err(Error::Loop)));
+}
@@ -0,0 +1,29 @@
+use cipher::{StreamCipher, StreamCipherFmtWriter};
+use core::fmt::Write;
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+#[test]
+fn fmt_writer() {
+    let mut ct = Vec::new();
+    let mut writer = StreamCipherFmtWriter::new(DummyStreamCipher::default(), |b: &[u8]| {
+        ct.extend_from_slice(b)
+    });
+    write!(writer, "{} + {} = {}, ", 2, 2, 4).unwrap();
+    writeln!(writer, "{:?}", "ключ").unwrap();
+    let (cipher, _) = writer.into_inner();
+    assert_eq!(cipher.pos, ct.len() as u64);
+
+    let expected = "2 + 2 = 4, \"ключ\"\n";
+    assert_ne!(ct, expected.as_bytes());
+    DummyStreamCipher::default().apply_keystream(&mut ct);
+    assert_eq!(String::from_utf8(ct).unwrap(), expected);
+}
+
+#[test]
+fn fmt_writer_end() {
+    let mut writer = StreamCipherFmtWriter::new(DummyStreamCipher::default(), |_: &[u8]| {});
+    let s = "a".repeat(KEYSTREAM_LEN as usize);
+    assert!(writer.write_str(&s).is_ok());
+    assert!(writer.write_str("b").is_err());
+}
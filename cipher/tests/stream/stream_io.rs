@@ -0,0 +1,100 @@
+use cipher::{StreamCipher, StreamCipherReader, StreamCipherWriter};
+use std::io::{Read, Write};
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+fn plaintext() -> Vec<u8> {
+    (0..KEYSTREAM_LEN as u32).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn writer_chunks() {
+    let pt = plaintext();
+    let mut ct = pt.clone();
+    DummyStreamCipher::default().apply_keystream(&mut ct);
+
+    for &chunk_size in &[1, 3, 7, 16, 17, 100, 511, 512, 513, 2000] {
+        let mut writer = StreamCipherWriter::new(DummyStreamCipher::default(), Vec::new());
+        for chunk in pt.chunks(chunk_size) {
+            writer.write_all(chunk).unwrap();
+        }
+        let (_, buf) = writer.finish().unwrap();
+        assert_eq!(buf, ct, "chunk size: {}", chunk_size);
+    }
+}
+
+#[test]
+fn writer_io_copy() {
+    let pt = plaintext();
+    let mut ct = pt.clone();
+    DummyStreamCipher::default().apply_keystream(&mut ct);
+
+    let mut writer = StreamCipherWriter::new(DummyStreamCipher::default(), Vec::new());
+    std::io::copy(&mut &pt[..], &mut writer).unwrap();
+    let (cipher, buf) = writer.finish().unwrap();
+    assert_eq!(buf, ct);
+    assert_eq!(cipher.pos, pt.len() as u64);
+}
+
+#[test]
+fn reader_chunks() {
+    let pt = plaintext();
+    let mut ct = pt.clone();
+    DummyStreamCipher::default().apply_keystream(&mut ct);
+
+    for &buf_size in &[1, 7, 16, 64, 2000, 4096] {
+        let mut reader = StreamCipherReader::new(DummyStreamCipher::default(), &ct[..]);
+        let mut res = Vec::new();
+        let mut buf = vec![0u8; buf_size];
+        loop {
+            let n = reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            res.extend_from_slice(&buf[..n]);
+        }
+        let (cipher, _) = reader.into_inner();
+        assert_eq!(res, pt, "buffer size: {}", buf_size);
+        assert_eq!(cipher.pos, pt.len() as u64);
+    }
+}
+
+/// Writer which fails after accepting `limit` bytes.
+struct FailingWriter {
+    buf: Vec<u8>,
+    limit: usize,
+}
+
+impl Write for FailingWriter {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        let n = data.len().min(self.limit - self.buf.len());
+        if n == 0 {
+            return Err(std::io::ErrorKind::WriteZero.into());
+        }
+        self.buf.extend_from_slice(&data[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn writer_poisoned_after_error() {
+    let pt = plaintext();
+    let inner = FailingWriter {
+        buf: Vec::new(),
+        limit: 100,
+    };
+    let mut writer = StreamCipherWriter::new(DummyStreamCipher::default(), inner);
+    writer.write_all(&pt[..64]).unwrap();
+
+    let err = writer.write(&pt[64..128]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+    assert_eq!(writer.get_ref().buf.len(), 100);
+
+    assert!(writer.write(&pt[128..]).is_err());
+    assert_eq!(writer.get_ref().buf.len(), 100);
+    assert!(writer.finish().is_err());
+}
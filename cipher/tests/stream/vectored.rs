@@ -0,0 +1,35 @@
+use cipher::{Ctr, Ctr128BE, FromKeyNonce, StreamCipher};
+use std::io::IoSliceMut;
+
+use crate::common::{DummyKeyedCipher, DummyStreamCipher};
+
+/// CTR mode buffers unused keystream bytes of the last block.
+fn ctr() -> Ctr<DummyKeyedCipher, Ctr128BE> {
+    Ctr::new(&[0x42; 16].into(), &[0x24; 16].into())
+}
+
+#[test]
+fn vectored_mid_block() {
+    let pt: Vec<u8> = (0..100u8).collect();
+    let mut ct = pt.clone();
+    ctr().apply_keystream(&mut ct);
+
+    for &lens in &[[3, 5, 92], [0, 13, 87], [9, 1, 90], [17, 0, 83]] {
+        let mut buf = pt.clone();
+        let (a, rest) = buf.split_at_mut(lens[0]);
+        let (b, c) = rest.split_at_mut(lens[1]);
+        let mut bufs = [IoSliceMut::new(a), IoSliceMut::new(b), IoSliceMut::new(c)];
+        ctr().apply_keystream_vectored(&mut bufs);
+        assert_eq!(buf, ct, "buffer lengths: {:?}", lens);
+    }
+}
+
+#[test]
+fn vectored_end() {
+    let mut a = [0u8; 500];
+    let mut b = [0u8; 501];
+    let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+    let res = DummyStreamCipher::default().try_apply_keystream_vectored(&mut bufs);
+    assert!(res.is_err());
+    assert_eq!(b, [0u8; 501]);
+}
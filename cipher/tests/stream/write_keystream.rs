@@ -0,0 +1,52 @@
+use cipher::StreamCipher;
+
+use crate::common::{DummyStreamCipher, KEYSTREAM_LEN};
+
+#[test]
+fn write_keystream() {
+    for &len in &[0, 1, 15, 16, 17, 100, KEYSTREAM_LEN as usize] {
+        let mut expected = vec![0u8; len];
+        DummyStreamCipher::default().apply_keystream(&mut expected);
+
+        let mut buf = vec![0xAAu8; len];
+        DummyStreamCipher::default().write_keystream(&mut buf);
+        assert_eq!(buf, expected, "len: {}", len);
+
+        let mut buf = vec![0x55u8; len];
+        DummyStreamCipher::default()
+            .try_write_keystream(&mut buf)
+            .unwrap();
+        assert_eq!(buf, expected, "len: {}", len);
+    }
+}
+
+#[test]
+fn write_keystream_continues() {
+    let mut expected = [0u8; 64];
+    DummyStreamCipher::default().apply_keystream(&mut expected);
+
+    let mut cipher = DummyStreamCipher::default();
+    let mut buf = [0xAAu8; 64];
+    cipher.write_keystream(&mut buf[..10]);
+    cipher.write_keystream(&mut buf[10..]);
+    assert_eq!(buf, expected);
+    assert_eq!(cipher.pos, 64);
+}
+
+#[test]
+fn try_write_keystream_end() {
+    let mut cipher = DummyStreamCipher::default();
+    cipher.write_keystream(&mut [0u8; 10]);
+
+    let mut buf = vec![0xAAu8; KEYSTREAM_LEN as usize];
+    assert!(cipher.try_write_keystream(&mut buf).is_err());
+    assert!(buf.iter().all(|&b| b == 0));
+    assert_eq!(cipher.pos, 10);
+
+    // the cipher is still usable after the error
+    let mut expected = [0u8; 20];
+    DummyStreamCipher::default().apply_keystream(&mut expected);
+    let mut buf = [0xAAu8; 10];
+    cipher.write_keystream(&mut buf);
+    assert_eq!(buf, expected[10..]);
+}
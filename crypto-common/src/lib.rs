@@ -15,11 +15,15 @@ extern crate std;
 
 #[cfg(feature = "rand_core")]
 pub use rand_core;
+#[cfg(feature = "zeroize")]
+pub use zeroize;
 
 use core::fmt;
 use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
 #[cfg(feature = "rand_core")]
 use rand_core::{CryptoRng, RngCore};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 /// Block on which [`BlockSizeUser`] implementors operate.
 pub type Block<B> = GenericArray<u8, <B as BlockSizeUser>::BlockSize>;
@@ -34,6 +38,15 @@ pub type Iv<B> = GenericArray<u8, <B as IvSizeUser>::IvSize>;
 pub trait BlockSizeUser {
     /// Size of the block in bytes.
     type BlockSize: ArrayLength<u8> + 'static;
+
+    /// Return block size in bytes.
+    #[inline(always)]
+    fn block_size() -> usize
+    where
+        Self: Sized,
+    {
+        Self::BlockSize::USIZE
+    }
 }
 
 impl<T: BlockSizeUser> BlockSizeUser for &T {
@@ -44,6 +57,161 @@ impl<T: BlockSizeUser> BlockSizeUser for &mut T {
     type BlockSize = T::BlockSize;
 }
 
+/// Assert that block sizes of `A` and `B` are equal.
+///
+/// The function is intended to be evaluated in a const context, so a block
+/// size mismatch results in a compilation error:
+///
+/// ```
+/// use crypto_common::{assert_same_block_size, BlockSizeUser};
+/// use generic_array::typenum::U16;
+///
+/// struct A;
+/// struct B;
+///
+/// impl BlockSizeUser for A {
+///     type BlockSize = U16;
+/// }
+///
+/// impl BlockSizeUser for B {
+///     type BlockSize = U16;
+/// }
+///
+/// const _: () = assert_same_block_size::<A, B>();
+/// ```
+///
+/// ```compile_fail
+/// use crypto_common::{assert_same_block_size, BlockSizeUser};
+/// use generic_array::typenum::{U8, U16};
+///
+/// struct A;
+/// struct B;
+///
+/// impl BlockSizeUser for A {
+///     type BlockSize = U16;
+/// }
+///
+/// impl BlockSizeUser for B {
+///     type BlockSize = U8;
+/// }
+///
+/// const _: () = assert_same_block_size::<A, B>();
+/// ```
+#[inline(always)]
+pub const fn assert_same_block_size<A: BlockSizeUser, B: BlockSizeUser>() {
+    assert!(
+        A::BlockSize::USIZE == B::BlockSize::USIZE,
+        "block sizes are not equal"
+    );
+}
+
+/// Number of blocks of `C` required to hold `len` bytes, i.e. `len` divided
+/// by the block size rounded up.
+///
+/// Unlike the common `(len + block_size - 1) / block_size` idiom this
+/// function does not overflow for `len` close to `usize::MAX`.
+#[inline(always)]
+pub const fn blocks_for_len<C: BlockSizeUser>(len: usize) -> usize {
+    len.div_ceil(C::BlockSize::USIZE)
+}
+
+/// Bridge type which implements [`BlockSizeUser`] for a block size given
+/// as a const generic parameter.
+///
+/// [`BlockSizeUser`] is implemented for block sizes from 1 to 64 bytes and
+/// for 128 and 256 bytes.
+///
+/// ```
+/// use crypto_common::{BlockSizeUser, ConstBlockSize};
+/// use generic_array::typenum::U16;
+///
+/// fn check<B: BlockSizeUser<BlockSize = U16>>() {}
+///
+/// check::<ConstBlockSize<16>>();
+/// assert_eq!(ConstBlockSize::<16>::block_size(), 16);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConstBlockSize<const N: usize>;
+
+macro_rules! impl_const_block_size {
+    ($($n:literal => $ty:ident,)*) => {
+        $(
+            impl BlockSizeUser for ConstBlockSize<$n> {
+                type BlockSize = generic_array::typenum::$ty;
+            }
+        )*
+    };
+}
+
+impl_const_block_size! {
+    1 => U1,
+    2 => U2,
+    3 => U3,
+    4 => U4,
+    5 => U5,
+    6 => U6,
+    7 => U7,
+    8 => U8,
+    9 => U9,
+    10 => U10,
+    11 => U11,
+    12 => U12,
+    13 => U13,
+    14 => U14,
+    15 => U15,
+    16 => U16,
+    17 => U17,
+    18 => U18,
+    19 => U19,
+    20 => U20,
+    21 => U21,
+    22 => U22,
+    23 => U23,
+    24 => U24,
+    25 => U25,
+    26 => U26,
+    27 => U27,
+    28 => U28,
+    29 => U29,
+    30 => U30,
+    31 => U31,
+    32 => U32,
+    33 => U33,
+    34 => U34,
+    35 => U35,
+    36 => U36,
+    37 => U37,
+    38 => U38,
+    39 => U39,
+    40 => U40,
+    41 => U41,
+    42 => U42,
+    43 => U43,
+    44 => U44,
+    45 => U45,
+    46 => U46,
+    47 => U47,
+    48 => U48,
+    49 => U49,
+    50 => U50,
+    51 => U51,
+    52 => U52,
+    53 => U53,
+    54 => U54,
+    55 => U55,
+    56 => U56,
+    57 => U57,
+    58 => U58,
+    59 => U59,
+    60 => U60,
+    61 => U61,
+    62 => U62,
+    63 => U63,
+    64 => U64,
+    128 => U128,
+    256 => U256,
+}
+
 /// Types which return data with the given size.
 pub trait OutputSizeUser {
     /// Size of the output in bytes.
@@ -58,6 +226,32 @@ pub trait KeySizeUser {
     type KeySize: ArrayLength<u8> + 'static;
 }
 
+/// Extension trait which exposes [`KeySizeUser::KeySize`] as a `usize`
+/// constant.
+///
+/// The constant is provided by a separate trait to keep [`KeySizeUser`]
+/// usable as a trait object. It's implemented for all sized
+/// [`KeySizeUser`] types.
+pub trait KeySizeUserExt: KeySizeUser {
+    /// Key size in bytes as a `usize` constant.
+    ///
+    /// ```
+    /// use crypto_common::{KeySizeUser, KeySizeUserExt};
+    /// use generic_array::typenum::U16;
+    ///
+    /// struct Aes128;
+    ///
+    /// impl KeySizeUser for Aes128 {
+    ///     type KeySize = U16;
+    /// }
+    ///
+    /// assert_eq!(Aes128::KEY_SIZE, 16);
+    /// ```
+    const KEY_SIZE: usize = Self::KeySize::USIZE;
+}
+
+impl<T: KeySizeUser> KeySizeUserExt for T {}
+
 /// Types which use initialization vector (nonce) for initialization.
 ///
 /// Generally it's used indirectly via [`KeyIvInit`] or [`InnerIvInit`].
@@ -66,6 +260,120 @@ pub trait IvSizeUser {
     type IvSize: ArrayLength<u8> + 'static;
 }
 
+/// Extension trait which exposes [`IvSizeUser::IvSize`] as a `usize`
+/// constant.
+///
+/// The constant is provided by a separate trait to keep [`IvSizeUser`]
+/// usable as a trait object. It's implemented for all sized
+/// [`IvSizeUser`] types.
+pub trait IvSizeUserExt: IvSizeUser {
+    /// Initialization vector size in bytes as a `usize` constant.
+    ///
+    /// ```
+    /// use crypto_common::{IvSizeUser, IvSizeUserExt};
+    /// use generic_array::typenum::U12;
+    ///
+    /// struct ChaCha20;
+    ///
+    /// impl IvSizeUser for ChaCha20 {
+    ///     type IvSize = U12;
+    /// }
+    ///
+    /// assert_eq!(ChaCha20::IV_SIZE, 12);
+    /// ```
+    const IV_SIZE: usize = Self::IvSize::USIZE;
+}
+
+impl<T: IvSizeUser> IvSizeUserExt for T {}
+
+/// Key wrapper which erases the key on drop.
+///
+/// The wrapper dereferences to [`Key`], so it can be passed directly to
+/// [`KeyInit::new`] and [`KeyIvInit::new`]. Its `Debug` implementation
+/// does not expose the key.
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub struct SecretKey<C: KeySizeUser>(Key<C>);
+
+#[cfg(feature = "zeroize")]
+impl<C: KeySizeUser> From<Key<C>> for SecretKey<C> {
+    #[inline]
+    fn from(key: Key<C>) -> Self {
+        Self(key)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: KeySizeUser> core::ops::Deref for SecretKey<C> {
+    type Target = Key<C>;
+
+    #[inline]
+    fn deref(&self) -> &Key<C> {
+        &self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: KeySizeUser> fmt::Debug for SecretKey<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey { .. }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: KeySizeUser> Drop for SecretKey<C> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: KeySizeUser> zeroize::ZeroizeOnDrop for SecretKey<C> {}
+
+/// Initialization vector wrapper which erases the IV on drop.
+///
+/// The wrapper dereferences to [`Iv`], so it can be passed directly to
+/// [`KeyIvInit::new`] and [`InnerIvInit::inner_iv_init`]. Its `Debug`
+/// implementation does not expose the IV.
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+pub struct SecretIv<C: IvSizeUser>(Iv<C>);
+
+#[cfg(feature = "zeroize")]
+impl<C: IvSizeUser> From<Iv<C>> for SecretIv<C> {
+    #[inline]
+    fn from(iv: Iv<C>) -> Self {
+        Self(iv)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: IvSizeUser> core::ops::Deref for SecretIv<C> {
+    type Target = Iv<C>;
+
+    #[inline]
+    fn deref(&self) -> &Iv<C> {
+        &self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: IvSizeUser> fmt::Debug for SecretIv<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretIv { .. }")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: IvSizeUser> Drop for SecretIv<C> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<C: IvSizeUser> zeroize::ZeroizeOnDrop for SecretIv<C> {}
+
 /// Types which use another type for initialization.
 ///
 /// Generally it's used indirectly via [`InnerInit`] or [`InnerIvInit`].
@@ -86,6 +394,51 @@ pub trait AlgorithmName {
     fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result;
 }
 
+/// Write `Debug` representation of `A` which contains only the algorithm
+/// name, i.e. `AlgName { .. }`, without exposing any state (e.g. key material).
+///
+/// See also the [`impl_debug_redacted!`] macro.
+#[inline]
+pub fn fmt_redacted<A: AlgorithmName>(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    A::write_alg_name(f)?;
+    f.write_str(" { .. }")
+}
+
+/// Implement `Debug` for a type which implements [`AlgorithmName`] using
+/// [`fmt_redacted`], i.e. without exposing any state (e.g. key material).
+///
+/// ```
+/// use core::fmt;
+/// use crypto_common::{impl_debug_redacted, AlgorithmName};
+///
+/// struct MyCipher {
+///     key: [u8; 16],
+/// }
+///
+/// impl AlgorithmName for MyCipher {
+///     fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str("MyCipher")
+///     }
+/// }
+///
+/// impl_debug_redacted!(MyCipher);
+///
+/// let cipher = MyCipher { key: [42; 16] };
+/// assert_eq!(format!("{:?}", cipher), "MyCipher { .. }");
+/// ```
+#[macro_export]
+macro_rules! impl_debug_redacted {
+    ($($name:ty),+ $(,)?) => {
+        $(
+            impl ::core::fmt::Debug for $name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    $crate::fmt_redacted::<Self>(f)
+                }
+            }
+        )+
+    };
+}
+
 /// Types which can be initialized from key.
 pub trait KeyInit: KeySizeUser + Sized {
     /// Create new value from fixed size key.
@@ -158,6 +511,26 @@ pub trait KeyIvInit: KeySizeUser + IvSizeUser + Sized {
     fn generate_key_iv(mut rng: impl CryptoRng + RngCore) -> (Key<Self>, Iv<Self>) {
         (Self::generate_key(&mut rng), Self::generate_iv(&mut rng))
     }
+
+    /// Create new value from random key and nonce generated using
+    /// the provided [`CryptoRng`].
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    #[inline]
+    fn new_random(rng: impl CryptoRng + RngCore) -> Self {
+        Self::new_random_with_key_iv(rng).0
+    }
+
+    /// Create new value from random key and nonce generated using
+    /// the provided [`CryptoRng`] and return it together with the generated
+    /// key and nonce.
+    #[cfg(feature = "rand_core")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+    #[inline]
+    fn new_random_with_key_iv(rng: impl CryptoRng + RngCore) -> (Self, Key<Self>, Iv<Self>) {
+        let (key, iv) = Self::generate_key_iv(rng);
+        (Self::new(&key, &iv), key, iv)
+    }
 }
 
 /// Types which can be initialized from another type (usually block ciphers).
@@ -273,5 +646,4 @@ impl fmt::Display for InvalidLength {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for InvalidLength {}
+impl core::error::Error for InvalidLength {}
@@ -0,0 +1,31 @@
+use crypto_common::BlockSizeUser;
+use generic_array::typenum::{U16, U64};
+
+struct Dummy16;
+
+impl BlockSizeUser for Dummy16 {
+    type BlockSize = U16;
+}
+
+struct Dummy64;
+
+impl BlockSizeUser for Dummy64 {
+    type BlockSize = U64;
+}
+
+fn block_size_of<T: BlockSizeUser>() -> usize {
+    T::block_size()
+}
+
+#[test]
+fn block_size() {
+    assert_eq!(Dummy16::block_size(), 16);
+    assert_eq!(Dummy64::block_size(), 64);
+    assert_eq!(block_size_of::<&Dummy16>(), 16);
+    assert_eq!(block_size_of::<&mut Dummy64>(), 64);
+}
+
+#[test]
+fn block_size_dyn() {
+    let _users: [&dyn BlockSizeUser<BlockSize = U16>; 2] = [&Dummy16, &&Dummy16];
+}
@@ -0,0 +1,28 @@
+use crypto_common::{blocks_for_len, BlockSizeUser, ConstBlockSize};
+use generic_array::typenum::U16;
+
+struct Dummy;
+
+impl BlockSizeUser for Dummy {
+    type BlockSize = U16;
+}
+
+#[test]
+fn blocks_for_len_boundaries() {
+    assert_eq!(blocks_for_len::<Dummy>(0), 0);
+    assert_eq!(blocks_for_len::<Dummy>(1), 1);
+    assert_eq!(blocks_for_len::<Dummy>(15), 1);
+    assert_eq!(blocks_for_len::<Dummy>(16), 1);
+    assert_eq!(blocks_for_len::<Dummy>(17), 2);
+    assert_eq!(blocks_for_len::<Dummy>(32), 2);
+    assert_eq!(blocks_for_len::<Dummy>(usize::MAX), usize::MAX / 16 + 1);
+    assert_eq!(blocks_for_len::<Dummy>(usize::MAX - 15), usize::MAX / 16);
+    assert_eq!(blocks_for_len::<ConstBlockSize<1>>(usize::MAX), usize::MAX);
+}
+
+#[test]
+fn blocks_for_len_const() {
+    const N: usize = blocks_for_len::<Dummy>(100);
+    let buf = [0u8; N];
+    assert_eq!(buf.len(), 7);
+}
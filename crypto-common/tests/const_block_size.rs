@@ -0,0 +1,18 @@
+use crypto_common::{Block, BlockSizeUser, ConstBlockSize};
+use generic_array::typenum::{U16, U32, U64, U8};
+
+fn check<B: BlockSizeUser<BlockSize = S>, S>() {}
+
+#[test]
+fn const_block_size() {
+    check::<ConstBlockSize<8>, U8>();
+    check::<ConstBlockSize<16>, U16>();
+    check::<ConstBlockSize<32>, U32>();
+    check::<ConstBlockSize<64>, U64>();
+
+    assert_eq!(ConstBlockSize::<8>::block_size(), 8);
+    assert_eq!(ConstBlockSize::<16>::block_size(), 16);
+    assert_eq!(ConstBlockSize::<32>::block_size(), 32);
+    assert_eq!(ConstBlockSize::<64>::block_size(), 64);
+    assert_eq!(Block::<ConstBlockSize<16>>::default().len(), 16);
+}
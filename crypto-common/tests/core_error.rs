@@ -0,0 +1,9 @@
+use core::error::Error;
+use crypto_common::InvalidLength;
+
+#[test]
+fn core_error() {
+    let err: &dyn Error = &InvalidLength;
+    assert_eq!(err.to_string(), "Invalid Length");
+    assert!(err.source().is_none());
+}
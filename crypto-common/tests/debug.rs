@@ -0,0 +1,28 @@
+use core::fmt;
+use crypto_common::{impl_debug_redacted, AlgorithmName};
+
+const KEY: [u8; 16] = [0xAB; 16];
+
+struct DummyCipher {
+    #[allow(dead_code)]
+    key: [u8; 16],
+}
+
+impl AlgorithmName for DummyCipher {
+    fn write_alg_name(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DummyCipher")
+    }
+}
+
+impl_debug_redacted!(DummyCipher);
+
+#[test]
+fn debug_redacted() {
+    let s = format!("{:?}", DummyCipher { key: KEY });
+    assert_eq!(s, "DummyCipher { .. }");
+    assert!(!s.contains("171"));
+    assert!(!s.to_lowercase().contains("ab"));
+
+    let s = format!("{:#?}", DummyCipher { key: KEY });
+    assert_eq!(s, "DummyCipher { .. }");
+}
@@ -0,0 +1,24 @@
+use crypto_common::{IvSizeUser, IvSizeUserExt, KeySizeUser, KeySizeUserExt};
+use generic_array::typenum::{U12, U16};
+
+struct Dummy;
+
+impl KeySizeUser for Dummy {
+    type KeySize = U16;
+}
+
+impl IvSizeUser for Dummy {
+    type IvSize = U12;
+}
+
+#[test]
+fn key_iv_size() {
+    assert_eq!(Dummy::KEY_SIZE, 16);
+    assert_eq!(Dummy::IV_SIZE, 12);
+}
+
+#[test]
+fn key_iv_size_dyn() {
+    let _key_user: &dyn KeySizeUser<KeySize = U16> = &Dummy;
+    let _iv_user: &dyn IvSizeUser<IvSize = U12> = &Dummy;
+}
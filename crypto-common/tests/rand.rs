@@ -0,0 +1,104 @@
+#![cfg(feature = "rand_core")]
+
+use crypto_common::rand_core::{impls, CryptoRng, Error, RngCore};
+use crypto_common::{Iv, IvSizeUser, Key, KeyInit, KeyIvInit, KeySizeUser};
+use generic_array::typenum::{U12, U16, U32};
+
+/// Deterministic RNG which returns incrementing bytes starting from the seed.
+struct CounterRng(u8);
+
+impl RngCore for CounterRng {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for b in dest.iter_mut() {
+            *b = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for CounterRng {}
+
+struct DummyBlockCipher;
+
+impl KeySizeUser for DummyBlockCipher {
+    type KeySize = U16;
+}
+
+impl KeyInit for DummyBlockCipher {
+    fn new(_key: &Key<Self>) -> Self {
+        Self
+    }
+}
+
+struct DummyCipher {
+    key: Key<Self>,
+    iv: Iv<Self>,
+}
+
+impl KeySizeUser for DummyCipher {
+    type KeySize = U32;
+}
+
+impl IvSizeUser for DummyCipher {
+    type IvSize = U12;
+}
+
+impl KeyIvInit for DummyCipher {
+    fn new(key: &Key<Self>, iv: &Iv<Self>) -> Self {
+        Self { key: *key, iv: *iv }
+    }
+}
+
+#[test]
+fn key_init_generate_key() {
+    let key = DummyBlockCipher::generate_key(CounterRng(7));
+    assert_eq!(key.len(), 16);
+    assert_eq!(key.as_slice(), &(7..23).collect::<Vec<u8>>()[..]);
+    assert_eq!(key, DummyBlockCipher::generate_key(CounterRng(7)));
+    assert_ne!(key, DummyBlockCipher::generate_key(CounterRng(8)));
+}
+
+#[test]
+fn key_iv_init_generate() {
+    let expected: Vec<u8> = (1..45).collect();
+
+    let key = DummyCipher::generate_key(CounterRng(1));
+    assert_eq!(key.len(), 32);
+    assert_eq!(key.as_slice(), &expected[..32]);
+
+    let iv = DummyCipher::generate_iv(CounterRng(1));
+    assert_eq!(iv.len(), 12);
+    assert_eq!(iv.as_slice(), &expected[..12]);
+
+    let (key, iv) = DummyCipher::generate_key_iv(CounterRng(1));
+    assert_eq!(key.as_slice(), &expected[..32]);
+    assert_eq!(iv.as_slice(), &expected[32..]);
+}
+
+#[test]
+fn new_random() {
+    let expected: Vec<u8> = (5..49).collect();
+
+    let cipher = DummyCipher::new_random(CounterRng(5));
+    assert_eq!(cipher.key.as_slice(), &expected[..32]);
+    assert_eq!(cipher.iv.as_slice(), &expected[32..]);
+
+    let (cipher, key, iv) = DummyCipher::new_random_with_key_iv(CounterRng(5));
+    assert_eq!(key, cipher.key);
+    assert_eq!(iv, cipher.iv);
+    assert_eq!(key.as_slice(), &expected[..32]);
+    assert_eq!(iv.as_slice(), &expected[32..]);
+}
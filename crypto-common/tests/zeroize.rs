@@ -0,0 +1,55 @@
+#![cfg(feature = "zeroize")]
+
+use core::mem::{size_of, MaybeUninit};
+use crypto_common::{Iv, IvSizeUser, Key, KeyIvInit, KeySizeUser, SecretIv, SecretKey};
+use generic_array::typenum::{U12, U32};
+
+struct DummyCipher {
+    key: Key<Self>,
+    iv: Iv<Self>,
+}
+
+impl KeySizeUser for DummyCipher {
+    type KeySize = U32;
+}
+
+impl IvSizeUser for DummyCipher {
+    type IvSize = U12;
+}
+
+impl KeyIvInit for DummyCipher {
+    fn new(key: &Key<Self>, iv: &Iv<Self>) -> Self {
+        Self { key: *key, iv: *iv }
+    }
+}
+
+/// Drop value in place and return its backing memory.
+fn drop_and_read<T, const N: usize>(val: T) -> [u8; N] {
+    assert_eq!(size_of::<T>(), N);
+    let mut slot = MaybeUninit::new(val);
+    let mut res = [0u8; N];
+    unsafe {
+        slot.assume_init_drop();
+        let ptr = slot.as_ptr() as *const u8;
+        for (i, b) in res.iter_mut().enumerate() {
+            *b = core::ptr::read_volatile(ptr.add(i));
+        }
+    }
+    res
+}
+
+#[test]
+fn secret_key_iv() {
+    let key = SecretKey::<DummyCipher>::from(Key::<DummyCipher>::from([0xAB; 32]));
+    let iv = SecretIv::<DummyCipher>::from(Iv::<DummyCipher>::from([0xCD; 12]));
+
+    let cipher = DummyCipher::new(&key, &iv);
+    assert_eq!(cipher.key, *key);
+    assert_eq!(cipher.iv, *iv);
+
+    assert_eq!(format!("{:?}", key), "SecretKey { .. }");
+    assert_eq!(format!("{:?}", iv), "SecretIv { .. }");
+
+    assert_eq!(drop_and_read::<_, 32>(key), [0u8; 32]);
+    assert_eq!(drop_and_read::<_, 12>(iv), [0u8; 12]);
+}